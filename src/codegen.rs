@@ -0,0 +1,226 @@
+//! Generator for the `typeinfo` module tree, out of the typeinfos.csv list of type names.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::error::Result;
+use crate::ktid::{ktid_hash, parse_number};
+
+/// Line of typeinfos.csv: kind, KTID and full type path, without headers
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct TypeInfoRecord {
+    pub typekind: String,
+    pub ktid: String,
+    pub typename: String,
+}
+
+impl TypeInfoRecord {
+    /// The KTID column when it can be parsed (`0x` hex or decimal), the hash of the type name otherwise
+    pub fn id(&self) -> u32 {
+        parse_number(&self.ktid).unwrap_or_else(|| ktid_hash(&self.typename, 31))
+    }
+
+    /// Path of the module in the tree. `TypeInfo::` is the root of the tree, other kinds get their own directory under it.
+    pub fn module_path(&self) -> Vec<String> {
+        let typename = self.typename.strip_prefix("TypeInfo::").unwrap_or(&self.typename);
+        typename.split("::").map(module_name).collect()
+    }
+}
+
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// Directory name of a segment of a type path: lowercased, `kt` prefixed if it starts with a digit (`3D` is `kt3d`)
+pub fn module_name(segment: &str) -> String {
+    let name: String = segment.trim().chars().map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '_' }).collect();
+
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("kt{}", name)
+    } else {
+        name
+    }
+}
+
+/// How a module is declared by its parent, keywords have to be raw identifiers
+fn module_ident(name: &str) -> String {
+    if KEYWORDS.contains(&name) {
+        format!("r#{}", name)
+    } else {
+        name.to_string()
+    }
+}
+
+pub fn read_csv<P: AsRef<Path>>(path: P) -> Result<Vec<TypeInfoRecord>> {
+    let mut reader = csv::ReaderBuilder::new().has_headers(false).from_path(path)?;
+    let records = reader.deserialize().collect::<std::result::Result<Vec<TypeInfoRecord>, _>>()?;
+
+    Ok(records.into_iter().filter(|record| !record.typename.trim().is_empty()).collect())
+}
+
+#[derive(Debug, Default)]
+struct Module {
+    id: Option<(u32, String)>,
+    /// Type path of the module, taken from the first record below it for modules that aren't in the CSV
+    name: Option<String>,
+    children: BTreeSet<String>,
+}
+
+/// Result of a generation
+#[derive(Debug, Default)]
+pub struct Generated {
+    /// Every file written, relative to the output directory
+    pub files: Vec<PathBuf>,
+    /// Records that would have overwritten the module of another record
+    pub conflicts: Vec<TypeInfoRecord>,
+}
+
+/// Write the module tree and the registry in `out_dir`, usually `src/typeinfo`. Existing modules are overwritten, the output only depends on the records.
+pub fn generate<P: AsRef<Path>>(records: &[TypeInfoRecord], out_dir: P) -> Result<Generated> {
+    let out_dir = out_dir.as_ref();
+    let mut generated = Generated::default();
+
+    let mut records = records.to_vec();
+    records.sort_by(|a, b| (&a.typename, &a.typekind).cmp(&(&b.typename, &b.typekind)));
+    records.dedup();
+
+    let mut modules: BTreeMap<Vec<String>, Module> = BTreeMap::new();
+    modules.insert(vec![], Module::default());
+
+    for record in &records {
+        let path = record.module_path();
+
+        let segments: Vec<&str> = record.typename.split("::").collect();
+        let root = segments.len() - path.len();
+
+        for depth in 0..path.len() {
+            let parent = modules.entry(path[..depth].to_vec()).or_default();
+            parent.children.insert(path[depth].clone());
+
+            if depth > 0 && parent.name.is_none() {
+                parent.name = Some(segments[..root + depth].join("::"));
+            }
+        }
+
+        let module = modules.entry(path).or_default();
+
+        match module.id {
+            Some(_) => generated.conflicts.push(record.clone()),
+            None => module.id = Some((record.id(), record.typename.clone())),
+        }
+    }
+
+    for (path, module) in &modules {
+        let mut output = String::new();
+
+        if path.is_empty() {
+            output.push_str("//! KTIDs of the TypeInfos, generated from typeinfos.csv with `rdb_tool generate-typeinfos`.\n\n#![allow(dead_code)]\n\nmod lookup;\nmod registry;\n\npub use lookup::*;\n\n");
+        }
+
+        let id = match (&module.id, &module.name) {
+            (Some((id, name)), _) => Some((*id, name, "")),
+            (None, Some(name)) => Some((ktid_hash(name, 31), name, ", the parent of the types below. It isn't part of typeinfos.csv")),
+            (None, None) => None,
+        };
+
+        if let Some((id, name, note)) = id {
+            output.push_str(&format!("use crate::ktid::KTID;\n\n/// KTID of `{}`{}\npub const ID: KTID = KTID({});\npub const NAME: &str = \"{}\";\n\n", name, note, id, name));
+            output.push_str("pub struct Type;\n\nimpl crate::typeinfo::TypeInfo for Type {\n    const ID: KTID = ID;\n    const NAME: &'static str = NAME;\n}\n");
+
+            if !module.children.is_empty() {
+                output.push('\n');
+            }
+        }
+
+        for child in &module.children {
+            output.push_str(&format!("pub mod {};\n", module_ident(child)));
+        }
+
+        let relative: PathBuf = path.iter().collect::<PathBuf>().join("mod.rs");
+        let file = out_dir.join(&relative);
+
+        std::fs::create_dir_all(file.parent().unwrap())?;
+        std::fs::write(&file, output)?;
+        generated.files.push(relative);
+    }
+
+    let mut registry: Vec<(u32, &str, &str)> = modules.values().filter_map(|module| module.id.as_ref()).map(|(id, name)| {
+        let kind = &records.iter().find(|record| &record.typename == name).unwrap().typekind;
+        (*id, kind.as_str(), name.as_str())
+    }).collect();
+    registry.sort();
+
+    let mut output = String::from("// Generated from typeinfos.csv with `rdb_tool generate-typeinfos`, do not edit by hand.\n\n/// Every known TypeInfo as (KTID, kind, full type path), sorted by KTID\npub(super) static TYPEINFOS: &[(u32, &str, &str)] = &[\n");
+
+    for (id, kind, name) in registry {
+        output.push_str(&format!("    (0x{:08x}, {:?}, {:?}),\n", id, kind, name));
+    }
+
+    output.push_str("];\n");
+
+    std::fs::write(out_dir.join("registry.rs"), output)?;
+    generated.files.push(PathBuf::from("registry.rs"));
+
+    Ok(generated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(typekind: &str, ktid: &str, typename: &str) -> TypeInfoRecord {
+        TypeInfoRecord { typekind: typekind.to_string(), ktid: ktid.to_string(), typename: typename.to_string() }
+    }
+
+    #[test]
+    fn module_names() {
+        assert_eq!(module_name("3D"), "kt3d");
+        assert_eq!(module_name("RenderTarget"), "rendertarget");
+        assert_eq!(module_ident("static"), "r#static");
+        assert_eq!(record("TypeInfo", "", "TypeInfo::Object::3D::Placeable::Light::Point").module_path(), ["object", "kt3d", "placeable", "light", "point"]);
+        assert_eq!(record("Foo", "", "Foo::Bar").module_path(), ["foo", "bar"]);
+    }
+
+    #[test]
+    fn ids() {
+        assert_eq!(record("TypeInfo", "0x5a22d01c", "whatever").id(), 0x5a22d01c);
+        assert_eq!(record("TypeInfo", "1512196124", "whatever").id(), 1512196124);
+        assert_eq!(record("TypeInfo", "", "TypeInfo::Object::Sound::Bank").id(), crate::typeinfo::object::sound::bank::ID.as_u32());
+    }
+
+    #[test]
+    fn generated_tree() {
+        let records = [
+            record("TypeInfo", "", "TypeInfo::Object::Render::Texture::Static"),
+            record("TypeInfo", "", "TypeInfo::Object::Render::Texture"),
+            record("TypeInfo", "", "TypeInfo::Object::3D::Placeable::Light::Point"),
+            record("Other", "0x10", "Other::Thing"),
+            record("TypeInfo", "0x20", "TypeInfo::Object::3d::Placeable::Light::Point"),
+        ];
+
+        let first = tempfile::tempdir().unwrap();
+        let second = tempfile::tempdir().unwrap();
+
+        let generated = generate(&records, first.path()).unwrap();
+        assert_eq!(generated.conflicts.len(), 1);
+
+        let mut reversed = records.to_vec();
+        reversed.reverse();
+        generate(&reversed, second.path()).unwrap();
+
+        for file in &generated.files {
+            assert_eq!(std::fs::read(first.path().join(file)).unwrap(), std::fs::read(second.path().join(file)).unwrap(), "{}", file.display());
+        }
+
+        let read = |path: &str| std::fs::read_to_string(first.path().join(path)).unwrap();
+
+        assert!(read("mod.rs").contains("mod registry;\n\npub use lookup::*;\n\npub mod object;\npub mod other;\n"));
+        assert_eq!(read("object/render/texture/static/mod.rs"), format!("use crate::ktid::KTID;\n\n/// KTID of `TypeInfo::Object::Render::Texture::Static`\npub const ID: KTID = KTID({});\npub const NAME: &str = \"TypeInfo::Object::Render::Texture::Static\";\n\npub struct Type;\n\nimpl crate::typeinfo::TypeInfo for Type {{\n    const ID: KTID = ID;\n    const NAME: &'static str = NAME;\n}}\n", crate::typeinfo::object::render::texture::r#static::ID.as_u32()));
+        assert!(read("object/render/texture/mod.rs").ends_with("}\n\npub mod r#static;\n"));
+        assert!(read("object/kt3d/placeable/light/mod.rs").starts_with("use crate::ktid::KTID;\n\n/// KTID of `TypeInfo::Object::3D::Placeable::Light`, the parent of the types below."));
+        assert!(read("object/kt3d/placeable/light/mod.rs").ends_with("}\n\npub mod point;\n"));
+        assert!(read("registry.rs").contains("    (0x00000010, \"Other\", \"Other::Thing\"),\n"));
+    }
+}
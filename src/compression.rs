@@ -0,0 +1,189 @@
+use std::{io::{self, Read, Write}, str::FromStr};
+
+use flate2::{read::ZlibDecoder, write::ZlibEncoder};
+
+// Koei Tecmo stores compressed payloads as a sequence of chunks, each one being:
+// u32 compressed size, u32 decompressed size, followed by the compressed block.
+const CHUNK_HEADER_SIZE: usize = 8;
+// Maximum amount of decompressed data in a chunk
+const CHUNK_SIZE: usize = 0x4000;
+
+/// Compression formats supported by the RDB flags
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Compression {
+    Zlib,
+    Lz4,
+}
+
+impl FromStr for Compression {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "zlib" => Ok(Compression::Zlib),
+            "lz4" => Ok(Compression::Lz4),
+            _ => Err(format!("Unknown compression '{}', expected zlib or lz4", s)),
+        }
+    }
+}
+
+fn invalid_data<S: Into<String>>(message: S) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.into())
+}
+
+fn decompress_chunks<F>(data: &[u8], size: usize, mut decode_chunk: F) -> io::Result<Vec<u8>>
+where
+    F: FnMut(&[u8], usize) -> io::Result<Vec<u8>>,
+{
+    // Both sizes come from the file, only trust them up to a chunk so corrupt entries fail instead of aborting on allocation
+    let mut output = Vec::with_capacity(size.min(CHUNK_SIZE));
+    let mut cursor = 0;
+
+    while output.len() < size {
+        let header = data.get(cursor..cursor + CHUNK_HEADER_SIZE).ok_or_else(|| invalid_data(format!("Compressed data ends at 0x{:x} before the chunk header", cursor)))?;
+
+        let compressed_size = u32::from_le_bytes([header[0], header[1], header[2], header[3]]) as usize;
+        let decompressed_size = u32::from_le_bytes([header[4], header[5], header[6], header[7]]) as usize;
+        cursor += CHUNK_HEADER_SIZE;
+
+        let chunk = data.get(cursor..cursor + compressed_size).ok_or_else(|| invalid_data(format!("Chunk at 0x{:x} is truncated", cursor)))?;
+        cursor += compressed_size;
+
+        if decompressed_size > size - output.len() {
+            return Err(invalid_data(format!("Chunk at 0x{:x} decompresses to 0x{:x} bytes, past the expected 0x{:x}", cursor - compressed_size, decompressed_size, size)));
+        }
+
+        let decompressed = decode_chunk(chunk, decompressed_size)?;
+
+        if decompressed.len() != decompressed_size {
+            return Err(invalid_data(format!("Chunk decompressed to 0x{:x} bytes, expected 0x{:x}", decompressed.len(), decompressed_size)));
+        }
+
+        output.extend_from_slice(&decompressed);
+    }
+
+    if output.len() != size {
+        return Err(invalid_data(format!("Decompressed 0x{:x} bytes, expected 0x{:x}", output.len(), size)));
+    }
+
+    Ok(output)
+}
+
+fn compress_chunks<F>(data: &[u8], mut encode_chunk: F) -> io::Result<Vec<u8>>
+where
+    F: FnMut(&[u8]) -> io::Result<Vec<u8>>,
+{
+    let mut output = Vec::with_capacity(data.len());
+
+    for chunk in data.chunks(CHUNK_SIZE) {
+        let compressed = encode_chunk(chunk)?;
+
+        output.extend_from_slice(&(compressed.len() as u32).to_le_bytes());
+        output.extend_from_slice(&(chunk.len() as u32).to_le_bytes());
+        output.extend_from_slice(&compressed);
+    }
+
+    Ok(output)
+}
+
+/// Compress a payload using the chunked layout of the requested format
+pub fn compress(compression: Compression, data: &[u8]) -> io::Result<Vec<u8>> {
+    match compression {
+        Compression::Zlib => compress_chunks(data, |chunk| {
+            let mut encoder = ZlibEncoder::new(Vec::new(), flate2::Compression::best());
+            encoder.write_all(chunk)?;
+            encoder.finish()
+        }),
+        Compression::Lz4 => compress_chunks(data, |chunk| Ok(lz4_flex::block::compress(chunk))),
+    }
+}
+
+/// Decompress a chunked zlib payload. `size` is the expected size of the decompressed data.
+pub fn decompress_zlib(data: &[u8], size: usize) -> io::Result<Vec<u8>> {
+    decompress_chunks(data, size, |chunk, decompressed_size| {
+        let mut decompressed = Vec::with_capacity(decompressed_size.min(CHUNK_SIZE));
+        // One byte past the expected size is enough to report the mismatch
+        ZlibDecoder::new(chunk).take(decompressed_size as u64 + 1).read_to_end(&mut decompressed)?;
+        Ok(decompressed)
+    })
+}
+
+/// Decompress a chunked LZ4 payload. `size` is the expected size of the decompressed data.
+pub fn decompress_lz4(data: &[u8], size: usize) -> io::Result<Vec<u8>> {
+    decompress_chunks(data, size, |chunk, decompressed_size| {
+        // A LZ4 block can't expand more than 255 times, anything above is a corrupt header
+        if decompressed_size > chunk.len().saturating_mul(255) {
+            return Err(invalid_data(format!("LZ4 chunk of 0x{:x} bytes can't decompress to 0x{:x} bytes", chunk.len(), decompressed_size)));
+        }
+
+        lz4_flex::block::decompress(chunk, decompressed_size).map_err(|err| invalid_data(err.to_string()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunk(compressed: &[u8], decompressed_size: usize) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.extend_from_slice(&(compressed.len() as u32).to_le_bytes());
+        out.extend_from_slice(&(decompressed_size as u32).to_le_bytes());
+        out.extend_from_slice(compressed);
+        out
+    }
+
+    fn zlib(data: &[u8]) -> Vec<u8> {
+        let mut encoder = ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(data).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    #[test]
+    fn zlib_chunks() {
+        let first = vec![0xAAu8; 0x100];
+        let second = b"koei tecmo".to_vec();
+
+        let mut data = chunk(&zlib(&first), first.len());
+        data.extend(chunk(&zlib(&second), second.len()));
+
+        let expected: Vec<u8> = first.iter().chain(second.iter()).copied().collect();
+        assert_eq!(decompress_zlib(&data, expected.len()).unwrap(), expected);
+    }
+
+    #[test]
+    fn lz4_chunks() {
+        let expected = b"abcabcabcabcabcabcabcabc".to_vec();
+        let data = chunk(&lz4_flex::block::compress(&expected), expected.len());
+
+        assert_eq!(decompress_lz4(&data, expected.len()).unwrap(), expected);
+    }
+
+    #[test]
+    fn compress_round_trip() {
+        let expected: Vec<u8> = (0..CHUNK_SIZE * 2 + 0x123).map(|i| (i % 0x61) as u8).collect();
+
+        let zlib = compress(Compression::Zlib, &expected).unwrap();
+        assert_eq!(decompress_zlib(&zlib, expected.len()).unwrap(), expected);
+
+        let lz4 = compress(Compression::Lz4, &expected).unwrap();
+        assert_eq!(decompress_lz4(&lz4, expected.len()).unwrap(), expected);
+    }
+
+    #[test]
+    fn truncated_data_is_rejected() {
+        let expected = vec![0u8; 0x40];
+        let mut data = chunk(&zlib(&expected), expected.len());
+        data.truncate(data.len() - 2);
+
+        assert!(decompress_zlib(&data, expected.len()).is_err());
+        assert!(decompress_zlib(&[], 4).is_err());
+    }
+
+    #[test]
+    fn corrupt_sizes_are_rejected() {
+        let data = chunk(&zlib(b"x"), 1);
+        assert!(decompress_zlib(&data, usize::MAX).is_err());
+        assert!(decompress_zlib(&chunk(&zlib(b"x"), u32::MAX as usize), 1).is_err());
+        assert!(decompress_lz4(&chunk(&[0x10, b'x'], u32::MAX as usize), usize::MAX).is_err());
+    }
+}
@@ -0,0 +1,236 @@
+//! Recovery of the names behind KTIDs, by hashing candidate names built from wordlists and numbered patterns.
+
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+use crate::ktid::{ktid_hash, KTID};
+use crate::namedb::NameDatabase;
+
+/// Numbered name like `chr%04d`, with a single printf-style `%d` or `%0<width>d` placeholder
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pattern {
+    before: String,
+    width: usize,
+    after: String,
+}
+
+impl FromStr for Pattern {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || Error::InvalidPattern(s.to_string());
+
+        let start = s.find('%').ok_or_else(invalid)?;
+        let end = start + s[start..].find('d').ok_or_else(invalid)?;
+
+        let width = match &s[start + 1..end] {
+            "" => 0,
+            width if width.starts_with('0') => width.parse().map_err(|_| invalid())?,
+            _ => return Err(invalid()),
+        };
+
+        let after = &s[end + 1..];
+
+        if after.contains('%') {
+            return Err(invalid());
+        }
+
+        Ok(Self { before: s[..start].to_string(), width, after: after.to_string() })
+    }
+}
+
+impl Pattern {
+    pub fn format(&self, number: u32) -> String {
+        let mut name = String::new();
+        self.format_into(number, &mut name);
+        name
+    }
+
+    /// Like [`Pattern::format`], reusing the allocation of `name`
+    pub fn format_into(&self, number: u32, name: &mut String) {
+        name.clear();
+        let _ = write!(name, "{}{:0width$}{}", self.before, number, self.after, width = self.width);
+    }
+
+    /// Every number that fits the width of the placeholder, 0 to 9999 without width
+    pub fn default_range(&self) -> RangeInclusive<u32> {
+        match self.width {
+            0 => 0..=9999,
+            width => 0..=10u32.checked_pow(width as u32).map_or(u32::MAX, |max| max - 1),
+        }
+    }
+
+    pub fn expand(&self, range: RangeInclusive<u32>) -> impl Iterator<Item = String> + '_ {
+        range.map(move |number| self.format(number))
+    }
+}
+
+/// How the candidate names are turned into the strings that get hashed
+#[derive(Debug, Clone, Default)]
+pub struct Cracker {
+    /// Tried in front of every name, in addition to the name alone
+    pub prefixes: Vec<String>,
+    /// Every name is also tried as a file with these extensions, both as `name.ext` and as `R_EXT［name］` like `KTID::new`
+    pub extensions: Vec<String>,
+    /// Amount of threads, every core if 0
+    pub threads: usize,
+}
+
+impl Cracker {
+    /// Every string hashed for a candidate name
+    pub fn variants(&self, name: &str) -> Vec<String> {
+        let mut variants = Vec::with_capacity((self.prefixes.len() + 1) * (self.extensions.len() * 2 + 1));
+        self.for_each_variant(name, &mut String::new(), |variant| variants.push(variant.to_string()));
+        variants
+    }
+
+    /// Call `visit` with every variant of the name, built in `buffer` so nothing is allocated per candidate
+    fn for_each_variant<F: FnMut(&str)>(&self, name: &str, buffer: &mut String, mut visit: F) {
+        for prefix in std::iter::once("").chain(self.prefixes.iter().map(String::as_str)) {
+            for extension in &self.extensions {
+                buffer.clear();
+                let _ = write!(buffer, "{}{}.{}", prefix, name, extension);
+                visit(buffer);
+
+                buffer.clear();
+                buffer.push_str("R_");
+                buffer.extend(extension.chars().flat_map(char::to_uppercase));
+                let _ = write!(buffer, "［{}{}］", prefix, name);
+                visit(buffer);
+            }
+
+            buffer.clear();
+            buffer.push_str(prefix);
+            buffer.push_str(name);
+
+            if !buffer.is_empty() {
+                visit(buffer);
+            }
+        }
+    }
+
+    fn threads(&self) -> usize {
+        match self.threads {
+            0 => std::thread::available_parallelism().map_or(1, |threads| threads.get()),
+            threads => threads,
+        }
+    }
+
+    /// Hash every variant of the names and of the numbers of the patterns, and keep the ones matching one of the targets.
+    /// The names of the patterns are generated by the threads as they go, each one taking a slice of the range, so large ranges don't have to fit in memory.
+    /// When several strings hash to the same KTID, the smallest one is kept so the result doesn't depend on the threads.
+    pub fn crack(&self, names: &[String], patterns: &[(Pattern, RangeInclusive<u32>)], targets: &HashSet<KTID>) -> NameDatabase {
+        let threads = self.threads();
+
+        let mut hits: Vec<(KTID, String)> = std::thread::scope(|scope| {
+            let workers: Vec<_> = (0..threads).map(|thread| {
+                scope.spawn(move || {
+                    let mut hits = vec![];
+                    let mut buffer = String::new();
+
+                    let mut try_name = |name: &str| self.for_each_variant(name, &mut buffer, |variant| {
+                        let hash = KTID(ktid_hash(variant, 31));
+
+                        if targets.contains(&hash) {
+                            hits.push((hash, variant.to_string()));
+                        }
+                    });
+
+                    names.iter().skip(thread).step_by(threads).for_each(|name| try_name(name));
+
+                    let mut name = String::new();
+
+                    for (pattern, range) in patterns {
+                        for number in split_range(range, thread, threads) {
+                            pattern.format_into(number, &mut name);
+                            try_name(&name);
+                        }
+                    }
+
+                    hits
+                })
+            }).collect();
+
+            workers.into_iter().flat_map(|worker| worker.join().unwrap()).collect()
+        });
+
+        hits.sort();
+        hits.dedup_by_key(|(ktid, _)| *ktid);
+
+        let mut database = NameDatabase::new();
+
+        for (ktid, name) in hits {
+            database.insert(ktid, name);
+        }
+
+        database
+    }
+}
+
+/// Slice of the range handled by one of the threads
+fn split_range(range: &RangeInclusive<u32>, thread: usize, threads: usize) -> impl Iterator<Item = u32> {
+    let start = *range.start() as u64;
+    let len = (*range.end() as u64 + 1).saturating_sub(start);
+
+    let first = start + len * thread as u64 / threads as u64;
+    let last = start + len * (thread as u64 + 1) / threads as u64;
+
+    // Both fit in a u32 as they're at most the end of the range, plus one for last
+    (first..last).map(|number| number as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ktid::ktid;
+
+    #[test]
+    fn patterns() {
+        let pattern: Pattern = "chr%04d_tex".parse().unwrap();
+        assert_eq!(pattern.format(42), "chr0042_tex");
+        assert_eq!(pattern.default_range(), 0..=9999);
+        assert_eq!(pattern.expand(8..=10).collect::<Vec<_>>(), ["chr0008_tex", "chr0009_tex", "chr0010_tex"]);
+
+        assert_eq!("ev%d".parse::<Pattern>().unwrap().format(123), "ev123");
+
+        for invalid in ["chr", "chr%4d", "chr%04", "%d%d"] {
+            assert!(matches!(invalid.parse::<Pattern>(), Err(Error::InvalidPattern(_))), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn split_ranges() {
+        for threads in 1..8 {
+            let numbers: Vec<u32> = (0..threads).flat_map(|thread| split_range(&(5..=100), thread, threads)).collect();
+            assert_eq!(numbers, (5..=100).collect::<Vec<u32>>());
+        }
+
+        assert_eq!(split_range(&(u32::MAX - 1..=u32::MAX), 1, 2).collect::<Vec<u32>>(), [u32::MAX]);
+        assert_eq!(split_range(&(0..=u32::MAX), 0, 4).size_hint().0, 1 << 30);
+    }
+
+    #[test]
+    fn cracks_every_variant() {
+        let cracker = Cracker { prefixes: vec!["stage/".to_string()], extensions: vec!["g1t".to_string()], threads: 3 };
+
+        let targets: HashSet<KTID> = vec![
+            ktid("chr0042").unwrap(),
+            ktid("stage/chr0007.g1t").unwrap(),
+            KTID::new("chr0100.g1t").unwrap(),
+            ktid("missing").unwrap(),
+        ].into_iter().collect();
+
+        let names: Vec<String> = "chr%04d".parse::<Pattern>().unwrap().expand(0..=200).collect();
+        let found = cracker.crack(&names, &[], &targets);
+
+        assert_eq!(found.len(), 3);
+        assert_eq!(cracker.crack(&[], &[("chr%04d".parse().unwrap(), 0..=200)], &targets), found);
+        assert_eq!(cracker.crack(&["missing".to_string()], &[], &targets).get(ktid("missing").unwrap()), Some("missing"));
+        assert_eq!(found.get(ktid("chr0042").unwrap()), Some("chr0042"));
+        assert_eq!(found.get(ktid("stage/chr0007.g1t").unwrap()), Some("stage/chr0007.g1t"));
+        assert_eq!(found.get(KTID::new("chr0100.g1t").unwrap()), Some("R_G1T［chr0100］"));
+    }
+}
@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::ktid::KTID;
+use crate::list::location_name;
+use crate::rdb::{Rdb, RdbEntry};
+
+/// Short description of an entry that was added or removed
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EntrySummary {
+    pub file_ktid: KTID,
+    pub type_info_ktid: KTID,
+    pub entry_type: u32,
+    pub file_size: u64,
+    pub name: String,
+}
+
+impl From<&RdbEntry> for EntrySummary {
+    fn from(entry: &RdbEntry) -> Self {
+        Self {
+            file_ktid: entry.file_ktid,
+            type_info_ktid: entry.type_info_ktid,
+            entry_type: entry.entry_type,
+            file_size: entry.file_size,
+            name: entry.get_name().into_owned(),
+        }
+    }
+}
+
+/// A field that differs between two versions of an entry
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FieldChange {
+    pub field: &'static str,
+    pub old: String,
+    pub new: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EntryChange {
+    pub file_ktid: KTID,
+    pub changes: Vec<FieldChange>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct RdbDiff {
+    pub added: Vec<EntrySummary>,
+    pub removed: Vec<EntrySummary>,
+    pub modified: Vec<EntryChange>,
+}
+
+impl RdbDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+    }
+}
+
+/// List the fields that differ between two entries with the same KTID
+pub fn diff_entries(old: &RdbEntry, new: &RdbEntry) -> Vec<FieldChange> {
+    let mut changes = vec![];
+
+    let mut compare = |field: &'static str, old: String, new: String| {
+        if old != new {
+            changes.push(FieldChange { field, old, new });
+        }
+    };
+
+    compare("file_size", format!("0x{:x}", old.file_size), format!("0x{:x}", new.file_size));
+    compare("entry_type", old.entry_type.to_string(), new.entry_type.to_string());
+    compare("type_info_ktid", format!("0x{:08x}", old.type_info_ktid), format!("0x{:08x}", new.type_info_ktid));
+    compare("storage", old.storage_mode().to_string(), new.storage_mode().to_string());
+    compare("location", location_name(old).to_string(), location_name(new).to_string());
+    compare("flags", format!("0x{:08x}", u32::from_le_bytes(old.flags.into_bytes())), format!("0x{:08x}", u32::from_le_bytes(new.flags.into_bytes())));
+    compare("name", old.get_name().into_owned(), new.get_name().into_owned());
+    compare("extra_info", hex(&old.unk_content), hex(&new.unk_content));
+
+    changes
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Match the entries of two RDBs by KTID
+pub fn diff(old: &Rdb, new: &Rdb) -> RdbDiff {
+    let old_entries: HashMap<KTID, &RdbEntry> = old.entries.iter().map(|entry| (entry.file_ktid, entry)).collect();
+    let new_entries: HashMap<KTID, &RdbEntry> = new.entries.iter().map(|entry| (entry.file_ktid, entry)).collect();
+
+    let mut result = RdbDiff::default();
+
+    for entry in &new.entries {
+        match old_entries.get(&entry.file_ktid) {
+            Some(old_entry) => {
+                let changes = diff_entries(old_entry, entry);
+
+                if !changes.is_empty() {
+                    result.modified.push(EntryChange { file_ktid: entry.file_ktid, changes });
+                }
+            },
+            None => result.added.push(entry.into()),
+        }
+    }
+
+    result.removed = old.entries.iter().filter(|entry| !new_entries.contains_key(&entry.file_ktid)).map(EntrySummary::from).collect();
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rdb::{testing::*, StorageMode};
+
+    #[test]
+    fn added_removed_modified() {
+        let old = Rdb::from_bytes(&synthetic_rdb("data", &[synthetic_entry(1, 0, "a@1", 1 << 16), synthetic_entry(2, 8, "b@1", 1 << 16), synthetic_entry(3, 0, "c@1", 1 << 16)])).unwrap();
+        let mut new = Rdb::from_bytes(&synthetic_rdb("data", &[synthetic_entry(1, 0, "a@1", 1 << 16), synthetic_entry(2, 8, "b@1", 1 << 16), synthetic_entry(4, 0, "d@1", 1 << 16)])).unwrap();
+
+        assert!(diff(&old, &old).is_empty());
+
+        new.entries[1].set_storage_mode(StorageMode::Lz4);
+        new.entries[1].file_size = 0x10;
+
+        let result = diff(&old, &new);
+
+        assert_eq!(result.added.len(), 1);
+        assert_eq!(result.added[0].file_ktid, KTID(4));
+        assert_eq!(result.removed.len(), 1);
+        assert_eq!(result.removed[0].file_ktid, KTID(3));
+        assert_eq!(result.modified.len(), 1);
+
+        let fields: Vec<&str> = result.modified[0].changes.iter().map(|change| change.field).collect();
+        assert_eq!(fields, ["file_size", "storage", "flags"]);
+
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(json["modified"][0]["file_ktid"], "0x00000002");
+        assert_eq!(json["modified"][0]["changes"][1]["new"], "lz4");
+    }
+}
@@ -0,0 +1,98 @@
+use std::{fmt, io, path::PathBuf};
+
+use crate::ktid::KTID;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    /// The magic of a RDB, IDRK or .fdata header didn't match
+    BadMagic { expected: u32, found: u32 },
+    /// Only version 0000 is supported
+    BadVersion(u32),
+    UnknownEntryType(u32),
+    /// The `0x????????.file` of an external entry couldn't be found
+    MissingExternalFile(PathBuf),
+    InvalidKtid(String),
+    /// Empty strings don't have a KTID
+    EmptyKtid,
+    /// KTIDs of paths are computed from their extension, like `R_G1T［name］`
+    MissingExtension(PathBuf),
+    NonUtf8Path(PathBuf),
+    /// The flags of the entry don't point to any location
+    NoLocation(KTID),
+    /// Encrypted entries can't be read or written
+    Encrypted(KTID),
+    /// The payload of an entry doesn't match the file size of the entry
+    SizeMismatch { ktid: KTID, expected: u64, found: u64 },
+    /// An IDRK blob was given for an entry with another KTID
+    KtidMismatch { expected: KTID, found: KTID },
+    /// Property type of an object database that isn't supported
+    UnknownPropertyType(u32),
+    /// Name pattern without a single `%d` or `%0<width>d` placeholder
+    InvalidPattern(String),
+    /// Any other parsing error
+    Parse(binread::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "{}", err),
+            Error::BadMagic { expected, found } => write!(f, "Bad magic: expected {:?}, found {:?}", String::from_utf8_lossy(&expected.to_le_bytes()), String::from_utf8_lossy(&found.to_le_bytes())),
+            Error::BadVersion(version) => write!(f, "Unsupported version {:?}", String::from_utf8_lossy(&version.to_le_bytes())),
+            Error::UnknownEntryType(entry_type) => write!(f, "Unknown entry type found: {}", entry_type),
+            Error::MissingExternalFile(path) => write!(f, "External file '{}' is missing", path.display()),
+            Error::InvalidKtid(ktid) => write!(f, "Invalid KTID '{}'", ktid),
+            Error::EmptyKtid => write!(f, "Can't compute the KTID of an empty string"),
+            Error::MissingExtension(path) => write!(f, "Can't compute the KTID of '{}' without an extension", path.display()),
+            Error::NonUtf8Path(path) => write!(f, "Path '{}' isn't valid UTF-8", path.display()),
+            Error::NoLocation(ktid) => write!(f, "Entry 0x{:08x} has no location", ktid),
+            Error::Encrypted(ktid) => write!(f, "Entry 0x{:08x} is encrypted", ktid),
+            Error::SizeMismatch { ktid, expected, found } => write!(f, "Entry 0x{:08x} is 0x{:x} bytes long, expected 0x{:x}", ktid, found, expected),
+            Error::KtidMismatch { expected, found } => write!(f, "IDRK blob is for 0x{:08x}, expected 0x{:08x}", found, expected),
+            Error::UnknownPropertyType(kind) => write!(f, "Unknown property type {}", kind),
+            Error::InvalidPattern(pattern) => write!(f, "Invalid pattern '{}', expected a single %d or %0<width>d placeholder", pattern),
+            Error::Parse(err) => write!(f, "{:?}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+impl From<csv::Error> for Error {
+    fn from(err: csv::Error) -> Self {
+        match err.into_kind() {
+            csv::ErrorKind::Io(err) => Error::Io(err),
+            kind => Error::Io(io::Error::new(io::ErrorKind::InvalidData, format!("{:?}", kind))),
+        }
+    }
+}
+
+impl From<binread::Error> for Error {
+    fn from(err: binread::Error) -> Self {
+        match err {
+            binread::Error::Io(err) => Error::Io(err),
+            // Errors raised by the asserts of our structures
+            binread::Error::Custom { err, pos } => match err.downcast::<Error>() {
+                Ok(err) => *err,
+                Err(err) => Error::Parse(binread::Error::Custom { err, pos }),
+            },
+            err => Error::Parse(err),
+        }
+    }
+}
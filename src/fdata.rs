@@ -0,0 +1,78 @@
+use crate::error::Result;
+use crate::idrk::Idrk;
+
+pub const FDATA_MAGIC: u32 = u32::from_le_bytes(*b"PDRK");
+pub const FDATA_VERSION: u32 = 0x30303030;
+pub const FDATA_HEADER_SIZE: u32 = 0x10;
+/// IDRK blobs are aligned on this inside a .fdata container
+pub const FDATA_ALIGNMENT: usize = 0x10;
+
+/// Builds a .fdata container out of IDRK blobs.
+/// Layout: magic, version, header size, file count, followed by the aligned blobs.
+#[derive(Debug, Default)]
+pub struct FdataWriter {
+    body: Vec<u8>,
+    file_count: u32,
+}
+
+impl FdataWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a blob and return its offset from the start of the container
+    pub fn push(&mut self, idrk: &Idrk) -> Result<u64> {
+        let padding = (FDATA_ALIGNMENT - self.body.len() % FDATA_ALIGNMENT) % FDATA_ALIGNMENT;
+        self.body.resize(self.body.len() + padding, 0);
+
+        let offset = FDATA_HEADER_SIZE as u64 + self.body.len() as u64;
+
+        self.body.extend(idrk.to_bytes()?);
+        self.file_count += 1;
+
+        Ok(offset)
+    }
+
+    pub fn file_count(&self) -> u32 {
+        self.file_count
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(FDATA_HEADER_SIZE as usize + self.body.len());
+
+        bytes.extend_from_slice(&FDATA_MAGIC.to_le_bytes());
+        bytes.extend_from_slice(&FDATA_VERSION.to_le_bytes());
+        bytes.extend_from_slice(&FDATA_HEADER_SIZE.to_le_bytes());
+        bytes.extend_from_slice(&self.file_count.to_le_bytes());
+        bytes.extend_from_slice(&self.body);
+
+        bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ktid::KTID;
+    use crate::rdb::StorageMode;
+
+    #[test]
+    fn blobs_are_aligned() {
+        let mut fdata = FdataWriter::new();
+
+        let first = Idrk::new(0, KTID(1), KTID(0), StorageMode::Uncompressed, b"abc").unwrap();
+        let second = Idrk::new(0, KTID(2), KTID(0), StorageMode::Uncompressed, b"defgh").unwrap();
+
+        assert_eq!(fdata.push(&first).unwrap(), 0x10);
+        let offset = fdata.push(&second).unwrap();
+        assert_eq!(offset % FDATA_ALIGNMENT as u64, 0);
+
+        let bytes = fdata.to_bytes();
+        assert_eq!(&bytes[..4], b"PDRK");
+        assert_eq!(fdata.file_count(), 2);
+
+        let parsed = Idrk::from_bytes(&bytes[offset as usize..]).unwrap();
+        assert_eq!(parsed.header.file_ktid, KTID(2));
+        assert_eq!(parsed.data, b"defgh");
+    }
+}
@@ -0,0 +1,169 @@
+use binread::{io::Cursor, BinRead, BinReaderExt};
+
+use binwrite::BinWrite;
+
+use crate::error::{Error, Result};
+use crate::ktid::KTID;
+use crate::rdb::{RdbEntry, RdbFlags, StorageMode};
+
+pub const IDRK_MAGIC: u32 = u32::from_le_bytes(*b"IDRK");
+pub const IDRK_VERSION: u32 = 0x30303030;
+/// Size of the fixed part of the header, before the extra info
+pub const IDRK_HEADER_SIZE: u32 = 0x30;
+
+/// Size of the extra info following the fixed header, based on the entry type
+pub fn extra_info_size(entry_type: u32) -> Option<u32> {
+    let header_size = match entry_type {
+        0 => 0x38,
+        // 1 is KidsSingletonDb? 4 is G1E
+        1 | 4 => 0x48,
+        // G1A, G1T
+        8 => 0x58,
+        // G1M, most likely other model related formats
+        12 => 0x68,
+        _ => return None,
+    };
+
+    Some(header_size - IDRK_HEADER_SIZE)
+}
+
+/// Same layout as the fixed part of a RDB entry, except the string size is the size of the stored payload
+#[derive(BinRead, BinWrite, Debug, Clone)]
+pub struct IdrkHeader {
+    #[br(assert(magic == IDRK_MAGIC, Error::BadMagic { expected: IDRK_MAGIC, found: magic }))]
+    pub magic: u32,
+    #[br(assert(version == IDRK_VERSION, Error::BadVersion(version)))]
+    pub version: u32,
+    pub entry_size: u32,
+    pub unk: u32,
+    pub content_size: u32,
+    pub unk2: u32,
+    pub file_size: u64,
+    pub entry_type: u32,
+    pub file_ktid: KTID,
+    pub type_info_ktid: KTID,
+    pub flags: RdbFlags,
+}
+
+/// IDRK blob, as found in external files and .fdata containers
+#[derive(BinRead, BinWrite, Debug, Clone)]
+#[br(little)]
+#[binwrite(little)]
+pub struct Idrk {
+    pub header: IdrkHeader,
+    #[br(count = header.entry_size.saturating_sub(header.content_size).saturating_sub(IDRK_HEADER_SIZE))]
+    pub extra_info: Vec<u8>,
+    #[br(count = header.content_size)]
+    pub data: Vec<u8>,
+}
+
+impl Idrk {
+    /// Build an IDRK blob for raw data, compressing it according to `mode`. The extra info is zeroed.
+    pub fn new(entry_type: u32, file_ktid: KTID, type_info_ktid: KTID, mode: StorageMode, data: &[u8]) -> Result<Self> {
+        let extra_size = extra_info_size(entry_type).ok_or(Error::UnknownEntryType(entry_type))?;
+
+        let mut flags = RdbFlags::new();
+        flags.set_storage_mode(mode);
+
+        let header = IdrkHeader {
+            magic: IDRK_MAGIC,
+            version: IDRK_VERSION,
+            entry_size: 0,
+            unk: 0,
+            content_size: 0,
+            unk2: 0,
+            file_size: 0,
+            entry_type,
+            file_ktid,
+            type_info_ktid,
+            flags,
+        };
+
+        Self::with_header(header, vec![0; extra_size as usize], data)
+    }
+
+    /// Build an IDRK blob for a RDB entry, reusing its extra info and storage mode
+    pub fn from_entry(entry: &RdbEntry, data: &[u8]) -> Result<Self> {
+        let mut flags = RdbFlags::new();
+        flags.set_storage_mode(entry.storage_mode());
+
+        let header = IdrkHeader {
+            magic: IDRK_MAGIC,
+            version: IDRK_VERSION,
+            entry_size: 0,
+            unk: entry.unk,
+            content_size: 0,
+            unk2: entry.unk2,
+            file_size: 0,
+            entry_type: entry.entry_type,
+            file_ktid: entry.file_ktid,
+            type_info_ktid: entry.type_info_ktid,
+            flags,
+        };
+
+        Self::with_header(header, entry.unk_content.clone(), data)
+    }
+
+    fn with_header(mut header: IdrkHeader, extra_info: Vec<u8>, data: &[u8]) -> Result<Self> {
+        if header.flags.storage_mode() == StorageMode::Encrypted {
+            return Err(Error::Encrypted(header.file_ktid));
+        }
+
+        let content = header.flags.storage_mode().encode(data)?;
+
+        header.file_size = data.len() as u64;
+        header.content_size = content.len() as u32;
+        header.entry_size = IDRK_HEADER_SIZE + extra_info.len() as u32 + header.content_size;
+
+        Ok(Self { header, extra_info, data: content })
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        Ok(Cursor::new(bytes).read_le()?)
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let mut bytes = Vec::with_capacity(self.header.entry_size as usize);
+        self.write(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// The payload, decompressed according to the flags of the header
+    pub fn decompressed_data(&self) -> Result<Vec<u8>> {
+        if self.header.flags.storage_mode() == StorageMode::Encrypted {
+            return Err(Error::Encrypted(self.header.file_ktid));
+        }
+
+        Ok(self.header.flags.storage_mode().decode(&self.data, self.header.file_size as usize)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrap_unwrap() {
+        let data = b"_M1G0037 model data".to_vec();
+
+        for mode in [StorageMode::Uncompressed, StorageMode::Zlib, StorageMode::Lz4] {
+            let idrk = Idrk::new(12, KTID(0x12345678), KTID(0x9abcdef0), mode, &data).unwrap();
+            assert_eq!(idrk.extra_info.len(), 0x38);
+
+            let bytes = idrk.to_bytes().unwrap();
+            assert_eq!(&bytes[..4], b"IDRK");
+            assert_eq!(bytes.len(), idrk.header.entry_size as usize);
+
+            let parsed = Idrk::from_bytes(&bytes).unwrap();
+            assert_eq!(parsed.header.file_ktid, KTID(0x12345678));
+            assert_eq!(parsed.header.flags.storage_mode(), mode);
+            assert_eq!(parsed.decompressed_data().unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn unknown_entry_type() {
+        assert!(matches!(Idrk::new(3, KTID(0), KTID(0), StorageMode::Uncompressed, &[]), Err(Error::UnknownEntryType(3))));
+        assert!(matches!(Idrk::from_bytes(b"_DRK0000"), Err(Error::BadMagic { .. })));
+    }
+}
@@ -1,8 +1,17 @@
+use std::convert::TryFrom;
 use std::fmt;
 use std::path::Path;
 use std::str::FromStr;
 
-#[derive(Debug, PartialEq)]
+use binread::BinRead;
+use binwrite::{BinWrite, WriterOption};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::error::{Error, Result};
+
+/// Serialized as a `0x????????` string
+#[allow(clippy::upper_case_acronyms)]
+#[derive(BinRead, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct KTID(pub u32);
 
 impl KTID {
@@ -10,9 +19,14 @@ impl KTID {
         self.0
     }
 
+    /// Display the KTID in one of the formats of the command line. Names are looked up with `resolver`.
+    pub fn display(self, format: KtidFormat, resolver: Option<&dyn KtidResolver>) -> KtidDisplay<'_> {
+        KtidDisplay { ktid: self, format, resolver }
+    }
+
     /// The argument is treated as a path, meaning you need to provide the filename and extension like a regular path.
-    pub fn new<P: AsRef<Path>>(name: P) -> Self {
-        KTID::from(name.as_ref())
+    pub fn new<P: AsRef<Path>>(name: P) -> Result<Self> {
+        KTID::try_from(name.as_ref())
     }
 }
 
@@ -22,64 +36,257 @@ impl From<u32> for KTID {
     }
 }
 
-impl From<&str> for KTID {
-    fn from(string: &str) -> Self {
+impl TryFrom<&str> for KTID {
+    type Error = Error;
+
+    fn try_from(string: &str) -> Result<Self> {
         ktid(string)
     }
 }
 
 impl FromStr for KTID {
-    type Err = ();
-    fn from_str(s: &str) -> Result<KTID, ()> {
-        Ok(KTID::from(s))
+    type Err = Error;
+    fn from_str(s: &str) -> Result<KTID> {
+        ktid(s)
     }
 }
 
-impl From<&Path> for KTID {
-    fn from(path: &Path) -> Self {
-        let mut buffer = Vec::<u8>::new();
-        buffer.extend_from_slice("R_".as_bytes());
-        // Huehuehue
-        buffer.extend_from_slice(
-            path.extension()
-                .unwrap()
-                .to_str()
-                .unwrap()
-                .to_uppercase()
-                .as_bytes(),
-        );
-        buffer.extend_from_slice("［".as_bytes());
-        buffer.extend_from_slice(path.file_stem().unwrap().to_str().unwrap().as_bytes());
-        buffer.extend_from_slice("］".as_bytes());
+impl TryFrom<&Path> for KTID {
+    type Error = Error;
 
-        ktid(&String::from_utf8(buffer).unwrap())
+    fn try_from(path: &Path) -> Result<Self> {
+        ktid(&path_string(path)?)
     }
 }
 
+/// String hashed for the KTID of a path, `R_<EXTENSION>［<stem>］`
+pub fn path_string(path: &Path) -> Result<String> {
+    let extension = path.extension().ok_or_else(|| Error::MissingExtension(path.to_path_buf()))?;
+    let stem = path.file_stem().ok_or_else(|| Error::MissingExtension(path.to_path_buf()))?;
+
+    let (extension, stem) = match (extension.to_str(), stem.to_str()) {
+        (Some(extension), Some(stem)) => (extension, stem),
+        _ => return Err(Error::NonUtf8Path(path.to_path_buf())),
+    };
+
+    // Huehuehue
+    Ok(format!("R_{}［{}］", extension.to_uppercase(), stem))
+}
+
 impl fmt::Display for KTID {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{:08x}", self.as_u32())
     }
 }
 
-pub fn ktid(string: &str) -> KTID {
-    if string.starts_with("0x") {
-        KTID(u32::from_str_radix(string.trim_start_matches("0x"), 16).unwrap())
+impl fmt::LowerHex for KTID {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.0, f)
+    }
+}
+
+impl fmt::UpperHex for KTID {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.0, f)
+    }
+}
+
+impl BinWrite for KTID {
+    fn write_options<W: std::io::Write>(&self, writer: &mut W, options: &WriterOption) -> std::io::Result<()> {
+        self.0.write_options(writer, options)
+    }
+}
+
+impl Serialize for KTID {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("0x{:08x}", self.0))
+    }
+}
+
+impl<'de> Deserialize<'de> for KTID {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+
+        string.strip_prefix("0x").and_then(|hex| u32::from_str_radix(hex, 16).ok()).map(KTID).ok_or_else(|| serde::de::Error::custom(format!("invalid KTID '{}', expected 0x????????", string)))
+    }
+}
+
+/// How KTIDs are shown on the command line
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KtidFormat {
+    /// `0x????????`
+    #[default]
+    Hex,
+    Decimal,
+    /// The name of the KTID, hex if it isn't known
+    Name,
+}
+
+impl FromStr for KtidFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "hex" => Ok(KtidFormat::Hex),
+            "decimal" => Ok(KtidFormat::Decimal),
+            "name" => Ok(KtidFormat::Name),
+            _ => Err(format!("Unknown KTID format '{}', expected hex, decimal or name", s)),
+        }
+    }
+}
+
+/// Finds the name of a KTID, such as a name database or the TypeInfo registry
+pub trait KtidResolver {
+    fn resolve(&self, ktid: KTID) -> Option<&str>;
+}
+
+impl<T: KtidResolver + ?Sized> KtidResolver for &T {
+    fn resolve(&self, ktid: KTID) -> Option<&str> {
+        (**self).resolve(ktid)
+    }
+}
+
+/// Asks the first resolver, then the second one
+impl<A: KtidResolver, B: KtidResolver> KtidResolver for (A, B) {
+    fn resolve(&self, ktid: KTID) -> Option<&str> {
+        self.0.resolve(ktid).or_else(|| self.1.resolve(ktid))
+    }
+}
+
+/// Returned by [`KTID::display`]
+pub struct KtidDisplay<'a> {
+    ktid: KTID,
+    format: KtidFormat,
+    resolver: Option<&'a dyn KtidResolver>,
+}
+
+impl fmt::Display for KtidDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self.format {
+            KtidFormat::Name => self.resolver.and_then(|resolver| resolver.resolve(self.ktid)),
+            _ => None,
+        };
+
+        let text = match (self.format, name) {
+            (_, Some(name)) => name.to_string(),
+            (KtidFormat::Decimal, _) => self.ktid.0.to_string(),
+            _ => format!("0x{:08x}", self.ktid),
+        };
+
+        // Through pad so the widths of the format string apply
+        f.pad(&text)
+    }
+}
+
+/// KTID of a file to patch. Files named after their KTID (`0x????????.ext`, or `0x????????.name.ext` as extracted with a name database) only keep the part before the first dot, as Cethleann keeps the extension even if the hash is missing.
+/// Any other file is hashed using its full filename.
+pub fn ktid_from_filename(path: &Path) -> Result<KTID> {
+    let filename = path.file_name().ok_or_else(|| Error::InvalidKtid(path.display().to_string()))?;
+    let filename = filename.to_str().ok_or_else(|| Error::NonUtf8Path(path.to_path_buf()))?;
 
+    if filename.starts_with("0x") {
+        ktid(filename.split('.').next().unwrap())
     } else {
-        KTID(ktid_hash(string, 31))
+        ktid(filename)
+    }
+}
+
+/// KTID written as a number, `0x` hex or decimal, like in the CSV files
+pub fn parse_number(string: &str) -> Option<u32> {
+    let string = string.trim();
+
+    match string.strip_prefix("0x") {
+        Some(hex) => u32::from_str_radix(hex, 16).ok(),
+        None => string.parse().ok(),
+    }
+}
+
+/// `0x` strings are parsed as hexadecimal KTIDs, anything else is hashed
+pub fn ktid(string: &str) -> Result<KTID> {
+    if string.is_empty() {
+        return Err(Error::EmptyKtid);
+    }
+
+    match string.strip_prefix("0x") {
+        Some(hex) => u32::from_str_radix(hex, 16).map(KTID).map_err(|_| Error::InvalidKtid(string.to_string())),
+        None => Ok(KTID(ktid_hash(string, 31))),
     }
 }
 
+/// The hash of an empty string is 0
 pub fn ktid_hash<T: AsRef<[u8]>>(text: T, mut key: i32) -> u32 {
-    let bytes = text.as_ref();
+    let (first, bytes) = match text.as_ref().split_first() {
+        Some(split) => split,
+        None => return 0,
+    };
 
-    let mut iv = bytes[0] as i32 * 31;
+    let mut iv = *first as i32 * 31;
 
-    for cur_char in &bytes[1..] {
+    for cur_char in bytes {
         iv = iv.wrapping_add(31i32.wrapping_mul(key.wrapping_mul((*cur_char as i8) as i32)));
         key = key.wrapping_mul(31);
     }
 
     iv as u32
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn value_type() {
+        let mut ktids = vec![KTID(3), KTID(1), KTID(2)];
+        ktids.sort();
+        assert_eq!(ktids, [KTID(1), KTID(2), KTID(3)]);
+        assert_eq!(ktids.iter().copied().collect::<std::collections::HashSet<KTID>>().len(), 3);
+
+        assert_eq!(serde_json::to_string(&KTID(0xbeef)).unwrap(), r#""0x0000beef""#);
+        assert_eq!(serde_json::from_str::<KTID>(r#""0x0000beef""#).unwrap(), KTID(0xbeef));
+        assert!(serde_json::from_str::<KTID>(r#""beef""#).is_err());
+
+        let mut bytes = vec![];
+        KTID(0x12345678).write(&mut bytes).unwrap();
+        assert_eq!(bytes, 0x12345678u32.to_le_bytes());
+
+        struct Names;
+
+        impl KtidResolver for Names {
+            fn resolve(&self, ktid: KTID) -> Option<&str> {
+                (ktid == KTID(0xbeef)).then_some("chr0001")
+            }
+        }
+
+        assert_eq!(format!("{:x}", KTID(0xbeef)), "beef");
+        assert_eq!(KTID(0xbeef).display(KtidFormat::Hex, Some(&Names)).to_string(), "0x0000beef");
+        assert_eq!(KTID(0xbeef).display(KtidFormat::Decimal, Some(&Names)).to_string(), "48879");
+        assert_eq!(KTID(0xbeef).display(KtidFormat::Name, Some(&Names)).to_string(), "chr0001");
+        assert_eq!(KTID(0xbeef).display(KtidFormat::Name, None).to_string(), "0x0000beef");
+        assert_eq!(KTID(1).display(KtidFormat::Name, Some(&Names)).to_string(), "0x00000001");
+        assert_eq!(format!("{:<12}|", KTID(1).display(KtidFormat::Hex, None)), "0x00000001  |");
+    }
+
+    #[test]
+    fn parsing_errors() {
+        assert_eq!(ktid("0x0000beef").unwrap(), KTID(0xbeef));
+        assert_eq!(ktid("chr0001").unwrap(), KTID(ktid_hash("chr0001", 31)));
+        assert!(matches!(ktid("0xnope"), Err(Error::InvalidKtid(_))));
+        assert!(matches!(ktid("0x"), Err(Error::InvalidKtid(_))));
+        assert!(matches!("".parse::<KTID>(), Err(Error::EmptyKtid)));
+        assert_eq!(ktid_hash("", 31), 0);
+
+        assert_eq!(path_string(Path::new("dir/chr0001.g1t")).unwrap(), "R_G1T［chr0001］");
+        assert_eq!(KTID::new("chr0001.g1t").unwrap(), ktid("R_G1T［chr0001］").unwrap());
+        assert!(matches!(KTID::new("chr0001"), Err(Error::MissingExtension(_))));
+        assert!(matches!(ktid_from_filename(Path::new("..")), Err(Error::InvalidKtid(_))));
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::ffi::OsStrExt;
+
+            let path = Path::new(std::ffi::OsStr::from_bytes(b"chr\xff.g1t"));
+            assert!(matches!(KTID::new(path), Err(Error::NonUtf8Path(_))));
+            assert!(matches!(ktid_from_filename(path), Err(Error::NonUtf8Path(_))));
+        }
+    }
+}
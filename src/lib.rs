@@ -0,0 +1,20 @@
+//! Library to read and patch the RDB archives used by Koei Tecmo games, along with the IDRK and .fdata containers they point to.
+
+pub mod codegen;
+pub mod compression;
+pub mod crack;
+pub mod diff;
+pub mod error;
+pub mod fdata;
+pub mod idrk;
+pub mod ktid;
+pub mod list;
+pub mod namedb;
+pub mod objdb;
+pub mod rdb;
+#[cfg(test)]
+mod samples;
+pub mod typeinfo;
+pub mod verify;
+
+pub use error::{Error, Result};
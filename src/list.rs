@@ -0,0 +1,125 @@
+use serde::Serialize;
+
+use crate::ktid::KTID;
+use crate::rdb::{RdbEntry, StorageMode};
+
+/// Where the payload of an entry is stored
+pub fn location_name(entry: &RdbEntry) -> &'static str {
+    match (entry.flags.external(), entry.flags.internal()) {
+        (true, _) => "external",
+        (false, true) => "internal",
+        (false, false) => "disabled",
+    }
+}
+
+/// Criteria an entry has to match to be listed. Empty lists and missing bounds match everything.
+#[derive(Debug, Clone, Default)]
+pub struct EntryFilter {
+    pub type_info_ktids: Vec<KTID>,
+    /// Type paths the TypeInfo of the entry has to be under, see [`crate::typeinfo::is_a`]
+    pub type_under: Vec<String>,
+    pub entry_types: Vec<u32>,
+    pub external: bool,
+    pub internal: bool,
+    pub compressed: bool,
+    pub min_size: Option<u64>,
+    pub max_size: Option<u64>,
+}
+
+impl EntryFilter {
+    pub fn matches(&self, entry: &RdbEntry) -> bool {
+        if !self.type_info_ktids.is_empty() && !self.type_info_ktids.contains(&entry.type_info_ktid) {
+            return false;
+        }
+
+        if !self.type_under.is_empty() && !self.type_under.iter().any(|ancestor| crate::typeinfo::is_a(entry.type_info_ktid, ancestor)) {
+            return false;
+        }
+
+        if !self.entry_types.is_empty() && !self.entry_types.contains(&entry.entry_type) {
+            return false;
+        }
+
+        // Asking for both external and internal entries lists everything that isn't disabled
+        if (self.external || self.internal) && !(self.external && entry.flags.external() || self.internal && entry.flags.internal()) {
+            return false;
+        }
+
+        if self.compressed && !matches!(entry.storage_mode(), StorageMode::Zlib | StorageMode::Lz4) {
+            return false;
+        }
+
+        self.min_size.is_none_or(|min| entry.file_size >= min) && self.max_size.is_none_or(|max| entry.file_size <= max)
+    }
+}
+
+/// Row of the `list` subcommand
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ListedEntry {
+    pub ktid: KTID,
+    pub type_info: KTID,
+    pub type_name: Option<String>,
+    pub entry_type: u32,
+    pub location: &'static str,
+    pub storage: String,
+    pub size: u64,
+    pub name: String,
+    /// Name of the file, when a name database or a dictionary knows it
+    pub file_name: Option<String>,
+}
+
+impl From<&RdbEntry> for ListedEntry {
+    fn from(entry: &RdbEntry) -> Self {
+        Self {
+            ktid: entry.file_ktid,
+            type_info: entry.type_info_ktid,
+            type_name: crate::typeinfo::name(entry.type_info_ktid).map(str::to_string),
+            entry_type: entry.entry_type,
+            location: location_name(entry),
+            storage: entry.storage_mode().to_string(),
+            size: entry.file_size,
+            name: entry.get_name().into_owned(),
+            file_name: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rdb::{testing::*, Rdb};
+
+    #[test]
+    fn filters() {
+        let mut rdb = Rdb::from_bytes(&synthetic_rdb("data", &[
+            synthetic_entry(1, 0, "a@1", 1 << 16),
+            synthetic_entry(2, 8, "b.fdata@10@1234", 1 << 17),
+            synthetic_entry(3, 12, "c@1", 0),
+        ])).unwrap();
+        rdb.entries[1].set_storage_mode(StorageMode::Zlib);
+        rdb.entries[2].file_size = 0x10;
+        rdb.entries[0].type_info_ktid = crate::typeinfo::object::kt3d::placeable::light::spot::ID;
+
+        let listed = |filter: EntryFilter| -> Vec<u32> { rdb.entries.iter().filter(|entry| filter.matches(entry)).map(|entry| entry.file_ktid.as_u32()).collect() };
+
+        assert_eq!(listed(EntryFilter::default()), [1, 2, 3]);
+        assert_eq!(listed(EntryFilter { entry_types: vec![8, 12], ..Default::default() }), [2, 3]);
+        assert_eq!(listed(EntryFilter { external: true, ..Default::default() }), [1]);
+        assert_eq!(listed(EntryFilter { external: true, internal: true, ..Default::default() }), [1, 2]);
+        assert_eq!(listed(EntryFilter { compressed: true, ..Default::default() }), [2]);
+        assert_eq!(listed(EntryFilter { min_size: Some(0x11), ..Default::default() }), [1, 2]);
+        assert_eq!(listed(EntryFilter { max_size: Some(0x10), ..Default::default() }), [3]);
+        assert_eq!(listed(EntryFilter { type_info_ktids: vec![KTID(0xabcdef01)], ..Default::default() }), [2, 3]);
+        assert_eq!(listed(EntryFilter { type_info_ktids: vec![KTID(0)], ..Default::default() }), Vec::<u32>::new());
+
+        assert_eq!(listed(EntryFilter { type_under: vec!["TypeInfo::Object::3D::Placeable::Light".to_string()], ..Default::default() }), [1]);
+        assert_eq!(listed(EntryFilter { type_under: vec!["TypeInfo::Object::Render".to_string()], ..Default::default() }), Vec::<u32>::new());
+
+        let row = ListedEntry::from(&rdb.entries[1]);
+        assert_eq!((row.location, row.storage.as_str()), ("internal", "zlib"));
+        assert_eq!(row.type_name, None);
+
+        rdb.entries[1].type_info_ktid = crate::typeinfo::object::render::texture::r#static::ID;
+        assert_eq!(ListedEntry::from(&rdb.entries[1]).type_name.as_deref(), Some("TypeInfo::Object::Render::Texture::Static"));
+    }
+}
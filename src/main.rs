@@ -1,16 +1,17 @@
-use std::{fs::File, fs::ReadDir, path::Path, path::PathBuf};
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
 
-use binread::{io::Cursor, BinRead};
-
-use binwrite::BinWrite;
-
-mod rdb;
-use rdb::Rdb;
-
-mod ktid;
-use ktid::{KTID, ktid};
-
-mod typeinfo;
+use rdb_tool::rdb::{Rdb, RdbEntry, StorageMode};
+use rdb_tool::ktid::{self, KtidFormat};
+use rdb_tool::compression::Compression;
+use rdb_tool::crack::{Cracker, Pattern};
+use rdb_tool::idrk::Idrk;
+use rdb_tool::fdata::FdataWriter;
+use rdb_tool::list::{EntryFilter, ListedEntry};
+use rdb_tool::namedb::{self, NameDatabase};
+use rdb_tool::objdb::ObjectDatabase;
+use rdb_tool::typeinfo;
 
 use structopt::StructOpt;
 
@@ -30,6 +31,32 @@ enum Command {
     Patch(Patch),
     /// Output relevant informations about a RDB entry
     Print(Print),
+    /// Extract the files referenced by a RDB
+    Extract(Extract),
+    /// Add or strip IDRK headers
+    Idrk(IdrkCommand),
+    /// Dump KIDS object databases to JSON (experimental, the layout isn't verified against shipped files)
+    Objdb(ObjdbCommand),
+    /// Add new files to a RDB
+    Add(Add),
+    /// Remove entries from a RDB
+    Remove(Remove),
+    /// Mark entries as unavailable without removing them
+    Disable(Remove),
+    /// Check a RDB for structural problems
+    Verify(Verify),
+    /// Compare the entries of two RDB files
+    Diff(Diff),
+    /// List the entries of a RDB
+    List(List),
+    /// Regenerate the typeinfo modules from a typeinfos.csv
+    GenerateTypeinfos(GenerateTypeinfos),
+    /// Recover the names of the entries of a RDB from wordlists and patterns
+    Crack(Crack),
+    /// Add names to a dictionary
+    Names(NamesCommand),
+    /// Compute the KTIDs of strings, file paths or TypeInfos
+    Hash(Hash),
 }
 
 #[derive(Debug, StructOpt)]
@@ -40,6 +67,12 @@ struct Patch {
     pub out_path: PathBuf,
     #[structopt(parse(from_os_str), default_value = "patch", help = "Directory where the files to patch are located")]
     pub data_path: PathBuf,
+    #[structopt(long, help = "Compress the patched files (zlib or lz4) instead of storing them uncompressed")]
+    pub compress: Option<Compression>,
+    #[structopt(long, help = "Replace entries that are encrypted in the RDB instead of skipping them")]
+    pub allow_encrypted: bool,
+    #[structopt(long, help = "Store the patched files as internal entries in a new .fdata file next to the output RDB, instead of external files")]
+    pub fdata: Option<String>,
 }
 
 #[derive(Debug, StructOpt)]
@@ -48,20 +81,457 @@ struct Print {
     pub path: PathBuf,
     #[structopt(help = "The KTID you would like to print")]
     pub ktid: String,
+    #[structopt(long, parse(from_os_str), help = "Name database used to resolve the KTIDs, in addition to the one next to the RDB")]
+    pub names: Option<PathBuf>,
+    #[structopt(long, default_value = "hex", help = "How the KTIDs are shown (hex, decimal or name)")]
+    pub ktid_format: KtidFormat,
 }
 
-fn patch_rdb(args: &Patch) -> Result<(), String> {
-    let mut rdb = Rdb::open(&args.path).unwrap();
+#[derive(Debug, StructOpt)]
+struct Extract {
+    #[structopt(parse(from_os_str), help = "Path to the RDB file")]
+    pub path: PathBuf,
+    #[structopt(parse(from_os_str), help = "Directory where the extracted files will be written")]
+    pub out_dir: PathBuf,
+    #[structopt(long, help = "Only extract the entries with these KTIDs")]
+    pub ktid: Vec<String>,
+    #[structopt(long, parse(from_os_str), help = "Name database used to resolve the KTIDs, in addition to the one next to the RDB")]
+    pub names: Option<PathBuf>,
+}
 
-    let external_path = if args.data_path.is_relative() {
+#[derive(Debug, StructOpt)]
+struct Add {
+    #[structopt(parse(from_os_str), help = "Path to the RDB file")]
+    pub path: PathBuf,
+    #[structopt(parse(from_os_str), help = "Output path to the RDB file")]
+    pub out_path: PathBuf,
+    #[structopt(parse(from_os_str), required = true, help = "Files to add. Their KTID is computed from the filename")]
+    pub files: Vec<PathBuf>,
+    #[structopt(long, help = "TypeInfo of the files, either as a name (TypeInfo::Object::Render::Texture::Static) or a KTID")]
+    pub type_info: String,
+    #[structopt(long, help = "Entry type of the files (0, 1, 4, 8 or 12). Guessed from the file contents if missing")]
+    pub entry_type: Option<u32>,
+    #[structopt(long, help = "KTID of the file, when adding a single file")]
+    pub ktid: Option<String>,
+    #[structopt(long, help = "Compress the added files (zlib or lz4)")]
+    pub compress: Option<Compression>,
+    #[structopt(long, help = "Store the added files as internal entries in a new .fdata file next to the output RDB, instead of external files")]
+    pub fdata: Option<String>,
+}
 
-        let rdb_dir = if args.path.is_relative() {
-            std::fs::canonicalize(&args.path).unwrap().parent().unwrap().to_path_buf()
-        } else {
-            args.path.parent().unwrap().to_path_buf()
-        };
+#[derive(Debug, StructOpt)]
+struct Remove {
+    #[structopt(parse(from_os_str), help = "Path to the RDB file")]
+    pub path: PathBuf,
+    #[structopt(parse(from_os_str), help = "Output path to the RDB file")]
+    pub out_path: PathBuf,
+    #[structopt(required = true, help = "KTIDs of the entries")]
+    pub ktids: Vec<String>,
+}
+
+#[derive(Debug, StructOpt)]
+struct Verify {
+    #[structopt(parse(from_os_str), help = "Path to the RDB file")]
+    pub path: PathBuf,
+}
+
+#[derive(Debug, StructOpt)]
+struct Diff {
+    #[structopt(parse(from_os_str), help = "Path to the original RDB file")]
+    pub old_path: PathBuf,
+    #[structopt(parse(from_os_str), help = "Path to the modified RDB file")]
+    pub new_path: PathBuf,
+    #[structopt(long, help = "Output the differences as JSON")]
+    pub json: bool,
+}
+
+#[derive(Debug, StructOpt)]
+struct List {
+    #[structopt(parse(from_os_str), help = "Path to the RDB file")]
+    pub path: PathBuf,
+    #[structopt(long, help = "Only list the entries of these TypeInfos, either as a name (TypeInfo::Object::Render::Texture::Static) or a KTID")]
+    pub type_info: Vec<String>,
+    #[structopt(long, help = "Only list the entries whose TypeInfo is or inherits from these, either as a path (TypeInfo::Object::3D::Placeable::Light) or a KTID")]
+    pub type_under: Vec<String>,
+    #[structopt(long, help = "Only list the entries with these entry types")]
+    pub entry_type: Vec<u32>,
+    #[structopt(long, help = "Only list external entries")]
+    pub external: bool,
+    #[structopt(long, help = "Only list internal entries")]
+    pub internal: bool,
+    #[structopt(long, help = "Only list compressed entries")]
+    pub compressed: bool,
+    #[structopt(long, parse(try_from_str = parse_size), help = "Only list the entries at least this big (decimal or 0x hex)")]
+    pub min_size: Option<u64>,
+    #[structopt(long, parse(try_from_str = parse_size), help = "Only list the entries at most this big (decimal or 0x hex)")]
+    pub max_size: Option<u64>,
+    #[structopt(long, default_value = "table", help = "Output format (table, json or csv)")]
+    pub format: OutputFormat,
+    #[structopt(long, parse(from_os_str), help = "Name database used to resolve the KTIDs, in addition to the one next to the RDB")]
+    pub names: Option<PathBuf>,
+    #[structopt(long, default_value = "hex", help = "How the KTIDs of the table are shown (hex, decimal or name). JSON and CSV always use hex")]
+    pub ktid_format: KtidFormat,
+}
+
+#[derive(Debug, StructOpt)]
+struct GenerateTypeinfos {
+    #[structopt(parse(from_os_str), required = true, help = "CSV files listing the typekind, KTID and typename of every TypeInfo, without headers")]
+    pub csv: Vec<PathBuf>,
+    #[structopt(long, parse(from_os_str), default_value = "src/typeinfo", help = "Directory of the typeinfo module")]
+    pub out_dir: PathBuf,
+}
+
+#[derive(Debug, StructOpt)]
+struct Crack {
+    #[structopt(parse(from_os_str), help = "Path to the RDB file")]
+    pub path: PathBuf,
+    #[structopt(long, parse(from_os_str), help = "Files listing a candidate name per line")]
+    pub wordlist: Vec<PathBuf>,
+    #[structopt(long, help = "Candidate names")]
+    pub word: Vec<String>,
+    #[structopt(long, help = "Numbered candidate names, like chr%04d")]
+    pub pattern: Vec<String>,
+    #[structopt(long, parse(try_from_str = parse_range), help = "Numbers tried in the patterns, like 0-9999. Every number fitting the placeholder if missing")]
+    pub range: Option<RangeInclusive<u32>>,
+    #[structopt(long, help = "Prefixes tried in front of every candidate, like a directory")]
+    pub prefix: Vec<String>,
+    #[structopt(long, help = "Extensions tried for every candidate, like g1t")]
+    pub extension: Vec<String>,
+    #[structopt(long, default_value = "0", help = "Amount of threads, every core if 0")]
+    pub threads: usize,
+    #[structopt(long, help = "Also try to find the KTIDs that already have a name")]
+    pub all: bool,
+    #[structopt(long, parse(from_os_str), help = "Dictionary (.csv) or name database where the names found are added. Defaults to the dictionary of the configuration directory, which is loaded automatically")]
+    pub out: Option<PathBuf>,
+}
+
+#[derive(Debug, StructOpt)]
+enum NamesCommand {
+    /// Add names, their KTID being their hash
+    Add(NamesAdd),
+    /// Add the names of CSV files, filelists or hash conversion tables
+    Import(NamesImport),
+}
+
+#[derive(Debug, StructOpt)]
+struct NamesAdd {
+    #[structopt(required = true, help = "Names to add, like chr0001 or R_G1T［chr0001］")]
+    pub names: Vec<String>,
+    #[structopt(long, help = "KTID of the name, when adding a single name that isn't its hash")]
+    pub ktid: Option<String>,
+    #[structopt(long, parse(from_os_str), help = "Dictionary (.csv) or name database to edit. Defaults to the dictionary of the configuration directory")]
+    pub dictionary: Option<PathBuf>,
+}
+
+#[derive(Debug, StructOpt)]
+struct NamesImport {
+    #[structopt(parse(from_os_str), required = true, help = "Files to import")]
+    pub files: Vec<PathBuf>,
+    #[structopt(long, default_value = "csv", help = "Format of the files: csv (ktid,name), filelist (a name per line, hashed) or table (name = ktid)")]
+    pub format: ImportFormat,
+    #[structopt(long, parse(from_os_str), help = "Dictionary (.csv) or name database to edit. Defaults to the dictionary of the configuration directory")]
+    pub dictionary: Option<PathBuf>,
+}
+
+#[derive(Debug, StructOpt)]
+struct Hash {
+    #[structopt(help = "Strings to hash. Read from the standard input, one per line, if missing and no --csv is given")]
+    pub inputs: Vec<String>,
+    #[structopt(long, parse(from_os_str), help = "CSV files without headers holding the strings to hash")]
+    pub csv: Vec<PathBuf>,
+    #[structopt(long, default_value = "0", help = "Column of the CSV files holding the strings")]
+    pub column: usize,
+    #[structopt(long, default_value = "raw", help = "How the strings are hashed: raw, path (R_EXT［name］ of a file path) or typeinfo (TypeInfo::... path)")]
+    pub scheme: HashScheme,
+    #[structopt(long, default_value = "31", allow_hyphen_values = true, help = "Key of the hash. KTIDs always use 31")]
+    pub key: i32,
+    #[structopt(long, default_value = "table", help = "Output format (table, json or csv)")]
+    pub format: OutputFormat,
+    #[structopt(long, parse(from_os_str), help = "Name database or dictionary used for the reverse check, in addition to the one of the configuration directory")]
+    pub names: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum HashScheme {
+    Raw,
+    Path,
+    TypeInfo,
+}
+
+impl std::str::FromStr for HashScheme {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "raw" => Ok(HashScheme::Raw),
+            "path" => Ok(HashScheme::Path),
+            "typeinfo" => Ok(HashScheme::TypeInfo),
+            _ => Err(format!("Unknown hash scheme '{}', expected raw, path or typeinfo", s)),
+        }
+    }
+}
+
+impl HashScheme {
+    /// String actually hashed for an input
+    fn hashed_string(self, input: &str) -> Result<String, String> {
+        match self {
+            HashScheme::Raw if input.is_empty() => Err(rdb_tool::Error::EmptyKtid.to_string()),
+            HashScheme::Raw => Ok(input.to_string()),
+            HashScheme::Path => ktid::path_string(Path::new(input)).map_err(|err| err.to_string()),
+            // Known TypeInfos are written with their original case, which is part of the hash
+            HashScheme::TypeInfo => match typeinfo::ktid(input).and_then(typeinfo::name) {
+                Some(path) => Ok(path.to_string()),
+                None if input.starts_with("TypeInfo::") => Ok(input.to_string()),
+                None => Ok(format!("TypeInfo::{}", input)),
+            },
+        }
+    }
+}
+
+/// Row of the `hash` subcommand
+#[derive(Debug, serde::Serialize)]
+struct HashedString {
+    input: String,
+    hashed: String,
+    ktid: ktid::KTID,
+    decimal: u32,
+    /// Name the KTID resolves to in the registry or the dictionaries
+    known: Option<String>,
+    /// `match` if the KTID resolves back to the hashed string, `collision` if it resolves to something else
+    check: &'static str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ImportFormat {
+    Csv,
+    Filelist,
+    Table,
+}
+
+impl std::str::FromStr for ImportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "csv" => Ok(ImportFormat::Csv),
+            "filelist" => Ok(ImportFormat::Filelist),
+            "table" => Ok(ImportFormat::Table),
+            _ => Err(format!("Unknown import format '{}', expected csv, filelist or table", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum OutputFormat {
+    Table,
+    Json,
+    Csv,
+}
+
+impl std::str::FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "table" => Ok(OutputFormat::Table),
+            "json" => Ok(OutputFormat::Json),
+            "csv" => Ok(OutputFormat::Csv),
+            _ => Err(format!("Unknown output format '{}', expected table, json or csv", s)),
+        }
+    }
+}
+
+/// KTID given on the command line, as `0x` hex or as a string to hash
+fn parse_ktid(ktid: &str) -> Result<ktid::KTID, String> {
+    ktid::ktid(ktid).map_err(|err| err.to_string())
+}
+
+/// TypeInfo given as a KTID or a type path, looked up in the registry first so the case doesn't matter
+fn parse_type_info(type_info: &str) -> Result<ktid::KTID, String> {
+    match typeinfo::ktid(type_info) {
+        Some(ktid) => Ok(ktid),
+        None => parse_ktid(type_info),
+    }
+}
+
+/// Type path of a TypeInfo or a group of TypeInfos, given as a path or a KTID
+fn parse_type_path(type_info: &str) -> Result<String, String> {
+    if type_info.starts_with("0x") {
+        let ktid = parse_ktid(type_info)?;
+        return typeinfo::path(ktid).map(str::to_string).ok_or_else(|| format!("Unknown TypeInfo {}", type_info));
+    }
+
+    if typeinfo::ktid(type_info).is_none() && typeinfo::descendants(type_info).next().is_none() {
+        return Err(format!("Unknown TypeInfo {}", type_info));
+    }
+
+    Ok(type_info.to_string())
+}
+
+fn parse_size(size: &str) -> Result<u64, String> {
+    match size.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(hex, 16),
+        None => size.parse(),
+    }.map_err(|_| format!("Invalid size '{}'", size))
+}
+
+fn parse_range(range: &str) -> Result<RangeInclusive<u32>, String> {
+    let invalid = || format!("Invalid range '{}', expected start-end", range);
+
+    let (start, end) = range.split_once('-').ok_or_else(invalid)?;
+    let (start, end) = (start.trim().parse().map_err(|_| invalid())?, end.trim().parse().map_err(|_| invalid())?);
+
+    if start > end {
+        return Err(invalid());
+    }
+
+    Ok(start..=end)
+}
+
+#[derive(Debug, StructOpt)]
+enum IdrkCommand {
+    /// Wrap a raw file in an IDRK header
+    Wrap(IdrkWrap),
+    /// Strip the IDRK header of a file
+    Unwrap(IdrkUnwrap),
+}
+
+#[derive(Debug, StructOpt)]
+struct IdrkWrap {
+    #[structopt(parse(from_os_str), help = "Path to the raw file")]
+    pub path: PathBuf,
+    #[structopt(parse(from_os_str), help = "Output path to the IDRK file")]
+    pub out_path: PathBuf,
+    #[structopt(long, help = "Entry type of the file (0, 1, 4, 8 or 12)")]
+    pub entry_type: u32,
+    #[structopt(long, help = "KTID of the file. Deduced from the filename if missing")]
+    pub ktid: Option<String>,
+    #[structopt(long, help = "KTID of the TypeInfo of the file")]
+    pub type_info: Option<String>,
+    #[structopt(long, help = "Compress the file (zlib or lz4)")]
+    pub compress: Option<Compression>,
+}
+
+#[derive(Debug, StructOpt)]
+struct IdrkUnwrap {
+    #[structopt(parse(from_os_str), help = "Path to the IDRK file")]
+    pub path: PathBuf,
+    #[structopt(parse(from_os_str), help = "Output path to the raw file")]
+    pub out_path: PathBuf,
+    #[structopt(long, help = "Keep the payload compressed instead of decompressing it")]
+    pub raw: bool,
+}
+
+#[derive(Debug, StructOpt)]
+enum ObjdbCommand {
+    /// Write the objects of a database as JSON
+    Dump(ObjdbDump),
+}
+
+#[derive(Debug, StructOpt)]
+struct ObjdbDump {
+    #[structopt(parse(from_os_str), help = "Path to the object database")]
+    pub path: PathBuf,
+    #[structopt(parse(from_os_str), help = "Output path to the JSON file")]
+    pub out_path: PathBuf,
+}
+
+/// Names given on the command line, completed by the ones found next to or inside the RDB
+fn load_names(rdb: &Rdb, rdb_path: &Path, names: Option<&Path>) -> Result<NameDatabase, String> {
+    let mut database = match names {
+        Some(path) => NameDatabase::open(path).map_err(|err| format!("Couldn't read the name database '{}': {}", path.display(), err))?,
+        None => NameDatabase::new(),
+    };
+
+    let (found, skipped) = NameDatabase::find_for_rdb(rdb, rdb_path).map_err(|err| format!("Couldn't read the name database of the RDB: {}", err))?;
+    database.merge(found);
+
+    if let Some((path, err)) = skipped {
+        eprintln!("Couldn't read the name database '{}': {}. Skipping.", path.display(), err);
+    }
+
+    merge_config_dictionary(&mut database)?;
+
+    Ok(database)
+}
+
+/// Complete names with the dictionary of the configuration directory, if there is one
+fn merge_config_dictionary(database: &mut NameDatabase) -> Result<(), String> {
+    if let Some(path) = namedb::dictionary_path().filter(|path| path.exists()) {
+        database.merge(NameDatabase::open(&path).map_err(|err| format!("Couldn't read the dictionary '{}': {}", path.display(), err))?);
+    }
+
+    Ok(())
+}
+
+/// Dictionary given on the command line, or the one of the configuration directory
+fn dictionary_path(path: Option<&Path>) -> Result<PathBuf, String> {
+    match path {
+        Some(path) => Ok(path.to_path_buf()),
+        None => namedb::dictionary_path().ok_or_else(|| "Couldn't find the configuration directory, set RDB_TOOL_CONFIG_DIR or give the path of a dictionary".to_string()),
+    }
+}
+
+/// Add names to a dictionary, creating it if needed
+fn update_dictionary(path: &Path, names: &NameDatabase) -> Result<(), String> {
+    let mut dictionary = match path.exists() {
+        true => NameDatabase::open(path).map_err(|err| format!("Couldn't read the dictionary '{}': {}", path.display(), err))?,
+        false => NameDatabase::new(),
+    };
+
+    for (ktid, name) in names.iter() {
+        dictionary.insert(ktid, name.to_string());
+    }
 
-        rdb_dir.join(&args.data_path)
+    dictionary.save(path).map_err(|err| format!("Couldn't write '{}': {}", path.display(), err))?;
+    println!("Added {} names to {}", names.len(), path.display());
+
+    Ok(())
+}
+
+fn rdb_directory(path: &Path) -> Result<PathBuf, String> {
+    let path = match path.is_relative() {
+        true => std::fs::canonicalize(path).map_err(|err| format!("Couldn't find '{}': {}", path.display(), err))?,
+        false => path.to_path_buf(),
+    };
+
+    path.parent().map(Path::to_path_buf).ok_or_else(|| format!("'{}' isn't in a directory", path.display()))
+}
+
+fn print_entry(args: &Print) -> Result<(), String> {
+    let rdb = Rdb::open(&args.path).map_err(|err| format!("Couldn't open the RDB: {}", err))?;
+    let entry = rdb.get_entry_by_ktid(parse_ktid(&args.ktid)?).ok_or_else(|| format!("File {} not found in the RDB.", args.ktid))?;
+
+    let names = load_names(&rdb, &args.path, args.names.as_deref())?;
+
+    println!("{:#?}", entry);
+    // TypeInfos are shown with their registry name, like the TypeInfo line
+    let resolver = (typeinfo::Registry, &names);
+
+    println!("KTID: {}", entry.file_ktid.display(args.ktid_format, Some(&resolver)));
+    println!("Name: {}", names.get(entry.file_ktid).unwrap_or("unknown"));
+    println!("TypeInfo: {}", typeinfo::name(entry.type_info_ktid).or_else(|| names.get(entry.type_info_ktid)).unwrap_or("unknown"));
+    println!("Storage: {}", entry.storage_mode());
+
+    match entry.extra_info() {
+        Some(extra_info) => {
+            println!("Extra info: {:#x?}", extra_info);
+
+            for dependency in &extra_info.dependencies {
+                println!("Dependency: {} ({})", dependency.file_ktid.display(args.ktid_format, Some(&resolver)), dependency.type_info_ktid.display(args.ktid_format, Some(&resolver)));
+            }
+        },
+        None => println!("Extra info: couldn't be decoded for entry type {}", entry.entry_type),
+    }
+
+    Ok(())
+}
+
+fn patch_rdb(args: &Patch) -> Result<(), String> {
+    let mut rdb = Rdb::open(&args.path).map_err(|err| format!("Couldn't open the RDB: {}", err))?;
+
+    let external_path = if args.data_path.is_relative() {
+        rdb_directory(&args.path)?.join(&args.data_path)
     } else {
         args.data_path.to_path_buf()
     };
@@ -75,9 +545,11 @@ fn patch_rdb(args: &Patch) -> Result<(), String> {
         Err(_) => return Err("How did you even managed to delete the directory this fast? Stop that.".to_string()),
     };
 
+    let mut fdata = args.fdata.as_ref().map(|_| FdataWriter::new());
+
     for entry in files {
-        let entry = entry.unwrap();
-        let metadata = entry.metadata().unwrap();
+        let entry = entry.map_err(|err| format!("Couldn't list the files to patch: {}", err))?;
+        let metadata = entry.metadata().map_err(|err| format!("Couldn't read '{}': {}", entry.path().display(), err))?;
 
         // We don't care about subdirectories
         if metadata.is_dir() {
@@ -86,132 +558,682 @@ fn patch_rdb(args: &Patch) -> Result<(), String> {
 
         let path = &entry.path();
 
-        // Check if we're dealing with a KTID or an actual filename
-        let filename = if path.file_name().unwrap().to_str().unwrap().starts_with("0x") {
-            // Strip the extension (Cethleann keeps the extension even if the hash is missing)
-            path.file_stem().unwrap().to_str().unwrap()
-        } else {
-            // Get the full filename with extension
-            path.file_name().unwrap().to_str().unwrap()
+        let filename = path.file_name().unwrap_or_else(|| path.as_os_str()).to_string_lossy();
+
+        let file_ktid = match ktid::ktid_from_filename(path) {
+            Ok(file_ktid) => file_ktid,
+            Err(err) => {
+                println!("{}. Skipping.", err);
+                continue;
+            },
         };
 
-        match rdb.get_entry_by_ktid_mut(crate::ktid(filename)) {
+        match rdb.get_entry_by_ktid_mut(file_ktid) {
             Some(entry_found) => {
+                if entry_found.storage_mode() == StorageMode::Encrypted {
+                    if !args.allow_encrypted {
+                        println!("File {} is encrypted in the RDB. Skipping (use --allow-encrypted to replace it anyway).", filename);
+                        continue;
+                    }
+
+                    println!("File {} is encrypted in the RDB, replacing it with unencrypted data.", filename);
+                }
+
                 println!("Patching {}", filename);
-                entry_found.make_external();
-                entry_found.make_uncompressed();
-                entry_found.set_external_file(&entry.path());
+
+                match args.compress {
+                    Some(compression) => entry_found.set_storage_mode(compression.into()),
+                    None => entry_found.make_uncompressed(),
+                }
+
+                store_entry_file(entry_found, path, output_directory(&args.out_path), fdata.as_mut().zip(args.fdata.as_deref()))?;
             },
             None => println!("File {} not found in the RDB. Skipping.", filename),
         }
     }
 
-    let mut bytes = vec![];
-    rdb.write(&mut bytes).unwrap();
+    if let Some(fdata) = fdata.as_ref().zip(args.fdata.as_deref()) {
+        write_fdata(fdata, &args.out_path)?;
+    }
 
-    std::fs::write(&args.out_path, bytes).unwrap();
+    save_rdb(&mut rdb, &args.out_path)?;
 
     Ok(())
 }
 
-fn main() {
-    let opt = Opt::from_args_safe().unwrap_or_else(|err| {
-        println!("{}", err);
-        std::process::exit(1);
-    });
+/// Store the file backing an entry, either as an external file or in the .fdata being built
+fn store_entry_file(entry: &mut RdbEntry, path: &Path, out_dir: &Path, fdata: Option<(&mut FdataWriter, &str)>) -> Result<(), String> {
+    match fdata {
+        Some((fdata, fdata_name)) => {
+            let data = std::fs::read(path).map_err(|err| format!("Couldn't read '{}': {}", path.display(), err))?;
 
-    match opt.cmd {
-        Command::Patch(args) => {
-            if let Err(error_msg) = patch_rdb(&args) {
-                println!("{}", error_msg);
-            }
+            entry.make_internal();
+
+            let idrk = entry.idrk_for(&data).map_err(|err| format!("Couldn't wrap '{}': {}", path.display(), err))?;
+            let offset = fdata.push(&idrk).map_err(|err| err.to_string())?;
+
+            entry.set_internal_file(fdata_name, offset, entry.file_size);
         },
-        Command::Print(args) => {
-            let ktid = ktid(&args.ktid);
-            let rdb = Rdb::read(&mut Cursor::new(&std::fs::read(&args.path).unwrap())).unwrap();
-            let entry = rdb.get_entry_by_ktid(ktid).unwrap();
-            println!("{:#?}", entry);
+        None => {
+            entry.make_external();
+            entry.set_external_file(path, out_dir).map_err(|err| format!("Couldn't patch '{}': {}", path.display(), err))?;
+
+            if entry.file_size == 0 {
+                println!("Filesize of {} is 0. Are you sure about that?", path.display());
+            }
         },
     }
+
+    Ok(())
 }
 
-mod tests {
-    use std::io::Write;
+/// Directory of the RDB being written, where its external files and .fdata go
+fn output_directory(out_path: &Path) -> &Path {
+    out_path.parent().unwrap_or_else(|| Path::new(""))
+}
 
-    use super::*;
+/// Write the .fdata next to the output RDB
+fn write_fdata((fdata, fdata_name): (&FdataWriter, &str), out_path: &Path) -> Result<(), String> {
+    let fdata_path = output_directory(out_path).join(fdata_name);
 
-    //const TEST_CONTENTS: &[u8] = include_bytes!("../system.rdb");
+    println!("Writing {} files to {}", fdata.file_count(), fdata_path.display());
+    std::fs::write(&fdata_path, fdata.to_bytes()).map_err(|err| format!("Couldn't write '{}': {}", fdata_path.display(), err))
+}
 
-    #[test]
-    fn test() {
-        let test = typeinfo::object::sound::bank::ID;
+fn add_entries(args: &Add) -> Result<(), String> {
+    let mut rdb = Rdb::open(&args.path).map_err(|err| format!("Couldn't open the RDB: {}", err))?;
+
+    let mut fdata = args.fdata.as_ref().map(|_| FdataWriter::new());
+
+    for path in &args.files {
+        let file_ktid = match &args.ktid {
+            Some(ktid) if args.files.len() == 1 => parse_ktid(ktid)?,
+            Some(_) => return Err("--ktid can only be used when adding a single file".to_string()),
+            None => ktid::ktid_from_filename(path).map_err(|err| err.to_string())?,
+        };
+
+        let entry_type = match args.entry_type {
+            Some(entry_type) => entry_type,
+            None => {
+                let data = std::fs::read(path).map_err(|err| format!("Couldn't read '{}': {}", path.display(), err))?;
+                guess_entry_type(&data)
+            },
+        };
+
+        let entry = RdbEntry::new(file_ktid, parse_type_info(&args.type_info)?, entry_type).map_err(|err| err.to_string())?;
+
+        let entry = match rdb.add_entry(entry) {
+            Some(entry) => entry,
+            None => {
+                println!("File {} is already in the RDB. Skipping.", path.display());
+                continue;
+            },
+        };
+
+        println!("Adding {} as 0x{:08x}", path.display(), entry.file_ktid);
+
+        if let Some(compression) = args.compress {
+            entry.set_storage_mode(compression.into());
+        }
+
+        store_entry_file(entry, path, output_directory(&args.out_path), fdata.as_mut().zip(args.fdata.as_deref()))?;
+    }
+
+    if let Some(fdata) = fdata.as_ref().zip(args.fdata.as_deref()) {
+        write_fdata(fdata, &args.out_path)?;
+    }
+
+    save_rdb(&mut rdb, &args.out_path)
+}
+
+fn save_rdb(rdb: &mut Rdb, path: &Path) -> Result<(), String> {
+    rdb.save(path).map_err(|err| format!("Couldn't write '{}': {}", path.display(), err))
+}
+
+fn remove_entries(args: &Remove) -> Result<(), String> {
+    let mut rdb = Rdb::open(&args.path).map_err(|err| format!("Couldn't open the RDB: {}", err))?;
+
+    for ktid in &args.ktids {
+        match rdb.remove_entry(parse_ktid(ktid)?) {
+            Some(entry) => println!("Removed 0x{:08x}", entry.file_ktid),
+            None => println!("File {} not found in the RDB. Skipping.", ktid),
+        }
+    }
+
+    save_rdb(&mut rdb, &args.out_path)
+}
+
+fn disable_entries(args: &Remove) -> Result<(), String> {
+    let mut rdb = Rdb::open(&args.path).map_err(|err| format!("Couldn't open the RDB: {}", err))?;
+
+    for ktid in &args.ktids {
+        match rdb.get_entry_by_ktid_mut(parse_ktid(ktid)?) {
+            Some(entry) => {
+                entry.disable();
+                println!("Disabled 0x{:08x}", entry.file_ktid);
+            },
+            None => println!("File {} not found in the RDB. Skipping.", ktid),
+        }
     }
 
-    use serde::Deserialize;
+    save_rdb(&mut rdb, &args.out_path)
+}
 
-    #[derive(Debug, Deserialize)]
-    pub struct TypeInfoEntry {
-        pub typekind: String,
-        ktid: String,
-        pub typename: String,
+/// Guess the entry type of a payload from its magic, which decides the size of the extra info
+fn guess_entry_type(data: &[u8]) -> u32 {
+    match data.get(..4) {
+        // Already wrapped, the header knows
+        Some(b"IDRK") => Idrk::from_bytes(data).map_or(0, |idrk| idrk.header.entry_type),
+        Some(b"_E1G") => 4,
+        Some(b"GT1G") | Some(b"_A1G") => 8,
+        Some(b"_M1G") => 12,
+        _ => 0,
     }
+}
 
-    #[test]
-    fn generate_typeinfos_lmao_gross() {
-        let mut rdr = csv::ReaderBuilder::new().has_headers(false).from_path("typeinfos.csv").unwrap();
+/// Guess the extension of a payload from its magic, falling back to the one Cethleann uses for unknown files
+fn guess_extension(data: &[u8]) -> &'static str {
+    match data.get(..4) {
+        Some(b"GT1G") => "g1t",
+        Some(b"_M1G") => "g1m",
+        Some(b"_A1G") => "g1a",
+        Some(b"_E1G") => "g1e",
+        Some(b"_S1G") => "g1s",
+        Some(b"KPS3") => "kps",
+        Some(b"KIDS") => "kidsobjdb",
+        Some(b"_NDB") => "ndb",
+        _ => "file",
+    }
+}
+
+/// Names can contain directories, which are flattened
+fn sanitize_filename(name: &str) -> String {
+    name.chars().map(|c| if matches!(c, '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|') || c.is_control() { '_' } else { c }).collect()
+}
+
+fn extract_rdb(args: &Extract) -> Result<(), String> {
+    let rdb = Rdb::open(&args.path).map_err(|err| format!("Couldn't open the RDB: {}", err))?;
+    let rdb_dir = rdb_directory(&args.path)?;
+
+    let filter: Vec<ktid::KTID> = args.ktid.iter().map(|ktid| parse_ktid(ktid)).collect::<Result<_, _>>()?;
+
+    let names = load_names(&rdb, &args.path, args.names.as_deref())?;
+
+    std::fs::create_dir_all(&args.out_dir).map_err(|err| format!("Couldn't create '{}': {}", args.out_dir.display(), err))?;
+
+    let mut failed = 0;
+
+    for entry in rdb.entries.iter().filter(|entry| filter.is_empty() || filter.contains(&entry.file_ktid)) {
+        // Disabled on purpose, there is nothing to read
+        if entry.is_disabled() {
+            println!("0x{:08x} is disabled. Skipping.", entry.file_ktid);
+            continue;
+        }
+
+        let data = match rdb.read_entry(entry, &rdb_dir) {
+            Ok(data) => data,
+            Err(err) => {
+                eprintln!("Couldn't read 0x{:08x}: {}. Skipping.", entry.file_ktid, err);
+                failed += 1;
+                continue;
+            },
+        };
+
+        // Named files keep their KTID first so they can be patched back as is
+        let filename = match names.get(entry.file_ktid) {
+            Some(name) => format!("0x{:08x}.{}.{}", entry.file_ktid, sanitize_filename(name), guess_extension(&data)),
+            None => format!("0x{:08x}.{}", entry.file_ktid, guess_extension(&data)),
+        };
+        let out_path = args.out_dir.join(filename);
+
+        println!("Extracting {}", out_path.display());
+        std::fs::write(&out_path, &data).map_err(|err| format!("Couldn't write '{}': {}", out_path.display(), err))?;
+    }
+
+    match failed {
+        0 => Ok(()),
+        failed => Err(format!("Couldn't extract {} entries", failed)),
+    }
+}
+
+fn idrk_wrap(args: &IdrkWrap) -> Result<(), String> {
+    let data = std::fs::read(&args.path).map_err(|err| format!("Couldn't read '{}': {}", args.path.display(), err))?;
+
+    let file_ktid = match &args.ktid {
+        Some(ktid) => parse_ktid(ktid)?,
+        None => ktid::ktid_from_filename(&args.path).map_err(|err| err.to_string())?,
+    };
+    let type_info_ktid = args.type_info.as_deref().map_or(Ok(ktid::KTID(0)), parse_type_info)?;
+    let mode = args.compress.map_or(StorageMode::Uncompressed, StorageMode::from);
+
+    let idrk = Idrk::new(args.entry_type, file_ktid, type_info_ktid, mode, &data).map_err(|err| err.to_string())?;
+    let bytes = idrk.to_bytes().map_err(|err| err.to_string())?;
+
+    std::fs::write(&args.out_path, bytes).map_err(|err| format!("Couldn't write '{}': {}", args.out_path.display(), err))
+}
+
+fn idrk_unwrap(args: &IdrkUnwrap) -> Result<(), String> {
+    let bytes = std::fs::read(&args.path).map_err(|err| format!("Couldn't read '{}': {}", args.path.display(), err))?;
+    let idrk = Idrk::from_bytes(&bytes).map_err(|err| format!("Couldn't parse the IDRK header: {}", err))?;
+
+    let data = if args.raw {
+        idrk.data
+    } else {
+        idrk.decompressed_data().map_err(|err| err.to_string())?
+    };
+
+    std::fs::write(&args.out_path, data).map_err(|err| format!("Couldn't write '{}': {}", args.out_path.display(), err))
+}
+
+fn objdb_dump(args: &ObjdbDump) -> Result<(), String> {
+    let database = ObjectDatabase::open(&args.path).map_err(|err| format!("Couldn't parse the object database '{}': {}", args.path.display(), err))?;
+    let json = serde_json::to_string_pretty(&database).map_err(|err| err.to_string())?;
+
+    std::fs::write(&args.out_path, json).map_err(|err| format!("Couldn't write '{}': {}", args.out_path.display(), err))
+}
+
+fn crack_names(args: &Crack) -> Result<(), String> {
+    let rdb = Rdb::open(&args.path).map_err(|err| format!("Couldn't open the RDB: {}", err))?;
+    let known = load_names(&rdb, &args.path, None)?;
+
+    let targets: HashSet<ktid::KTID> = rdb.entries.iter().map(|entry| entry.file_ktid).filter(|ktid| args.all || known.get(*ktid).is_none()).collect();
+
+    let mut candidates = args.word.clone();
+
+    for path in &args.wordlist {
+        let words = std::fs::read_to_string(path).map_err(|err| format!("Couldn't read '{}': {}", path.display(), err))?;
+        candidates.extend(words.lines().map(str::trim).filter(|word| !word.is_empty()).map(str::to_string));
+    }
+
+    let mut patterns = vec![];
+
+    for pattern in &args.pattern {
+        let pattern: Pattern = pattern.parse().map_err(|err: rdb_tool::Error| err.to_string())?;
+        let range = args.range.clone().unwrap_or_else(|| pattern.default_range());
+        patterns.push((pattern, range));
+    }
+
+    if candidates.is_empty() && patterns.is_empty() {
+        return Err("No candidates to try, use --word, --wordlist or --pattern".to_string());
+    }
+
+    let cracker = Cracker { prefixes: args.prefix.clone(), extensions: args.extension.clone(), threads: args.threads };
+
+    let count = candidates.len() as u64 + patterns.iter().map(|(_, range)| (*range.end() as u64 + 1).saturating_sub(*range.start() as u64)).sum::<u64>();
+    println!("Trying {} candidates against {} KTIDs", count * cracker.variants("_").len() as u64, targets.len());
+    let found = cracker.crack(&candidates, &patterns, &targets);
+
+    for (ktid, name) in found.iter() {
+        println!("0x{:08x} {}", ktid, name);
+    }
+
+    println!("Found {} names", found.len());
+
+    if found.is_empty() {
+        return Ok(());
+    }
+
+    update_dictionary(&dictionary_path(args.out.as_deref())?, &found)
+}
+
+fn names_add(args: &NamesAdd) -> Result<(), String> {
+    if args.ktid.is_some() && args.names.len() > 1 {
+        return Err("--ktid can only be used when adding a single name".to_string());
+    }
 
-        let typeinfos: Vec<String> = rdr.deserialize().into_iter().filter_map(|result| {
-            let record: TypeInfoEntry = result.unwrap();
+    let mut names = NameDatabase::new();
 
-            //dbg!(record);
-            if record.typekind == "TypeInfo" {
-                Some(record.typename)
-            } else {
-                None
+    for name in &args.names {
+        if name.is_empty() {
+            return Err("Names can't be empty".to_string());
+        }
+
+        let ktid = match &args.ktid {
+            Some(ktid) => parse_ktid(ktid)?,
+            None => ktid::KTID(ktid::ktid_hash(name, 31)),
+        };
+
+        println!("0x{:08x} {}", ktid, name);
+        names.insert(ktid, name.clone());
+    }
+
+    update_dictionary(&dictionary_path(args.dictionary.as_deref())?, &names)
+}
+
+fn hash_strings(args: &Hash) -> Result<(), String> {
+    let mut inputs = args.inputs.clone();
+
+    for path in &args.csv {
+        let mut reader = csv::ReaderBuilder::new().has_headers(false).flexible(true).from_path(path).map_err(|err| format!("Couldn't read '{}': {}", path.display(), err))?;
+
+        for record in reader.records() {
+            let record = record.map_err(|err| format!("Couldn't parse '{}': {}", path.display(), err))?;
+            inputs.extend(record.get(args.column).map(str::to_string));
+        }
+    }
+
+    if args.inputs.is_empty() && args.csv.is_empty() {
+        use std::io::BufRead;
+
+        for line in std::io::stdin().lock().lines() {
+            inputs.push(line.map_err(|err| format!("Couldn't read the standard input: {}", err))?);
+        }
+    }
+
+    let mut names = match &args.names {
+        Some(path) => NameDatabase::open(path).map_err(|err| format!("Couldn't read the name database '{}': {}", path.display(), err))?,
+        None => NameDatabase::new(),
+    };
+    merge_config_dictionary(&mut names)?;
+
+    let mut rows = vec![];
+
+    for input in inputs {
+        let hashed = match args.scheme.hashed_string(&input) {
+            Ok(hashed) => hashed,
+            Err(err) => {
+                eprintln!("Couldn't hash '{}': {}", input, err);
+                continue;
+            },
+        };
+
+        let ktid = ktid::KTID(ktid::ktid_hash(&hashed, args.key));
+
+        // Names are only known for the KTIDs, which all use the same key
+        let known = match args.key {
+            31 => typeinfo::name(ktid).or_else(|| names.get(ktid)).map(str::to_string),
+            _ => None,
+        };
+
+        let check = match &known {
+            Some(known) if *known == hashed => "match",
+            Some(_) => "collision",
+            None => "unknown",
+        };
+
+        rows.push(HashedString { input, hashed, ktid, decimal: ktid.as_u32(), known, check });
+    }
+
+    match args.format {
+        OutputFormat::Table => {
+            for row in &rows {
+                match (&row.known, row.check) {
+                    (Some(known), "collision") => println!("0x{:08x}  {:>10}  {:<9}  {}  (known as {})", row.ktid, row.decimal, row.check, row.hashed, known),
+                    _ => println!("0x{:08x}  {:>10}  {:<9}  {}", row.ktid, row.decimal, row.check, row.hashed),
+                }
             }
-        }).collect();
-
-        for typeinfo in &typeinfos {
-            let mut path = PathBuf::from(format!(".\\src\\{}", &typeinfo.replace("::", "\\")).to_lowercase());
-            std::fs::create_dir_all(&path).unwrap();
-            
-            let mut mod_path = path.join("mod.rs");
-
-            if !mod_path.exists() {
-                let mut file = std::fs::OpenOptions::new().create(true).write(true).open(&dbg!(&mod_path)).unwrap();
-                let stem = mod_path.parent().unwrap().file_stem().unwrap().to_str().unwrap();
-                file.write_all(format!("use crate::ktid::KTID;\n\npub const ID: KTID = KTID({});", crate::ktid::ktid(&typeinfo).as_u32()).as_bytes()).unwrap();
+        },
+        OutputFormat::Json => {
+            let json = serde_json::to_string_pretty(&rows).map_err(|err| err.to_string())?;
+            println!("{}", json);
+        },
+        OutputFormat::Csv => {
+            let mut writer = csv::Writer::from_writer(std::io::stdout());
+
+            for row in &rows {
+                writer.serialize(row).map_err(|err| err.to_string())?;
             }
+
+            writer.flush().map_err(|err| err.to_string())?;
+        },
+    }
+
+    Ok(())
+}
+
+fn names_import(args: &NamesImport) -> Result<(), String> {
+    let mut names = NameDatabase::new();
+
+    for path in &args.files {
+        let text = std::fs::read_to_string(path).map_err(|err| format!("Couldn't read '{}': {}", path.display(), err))?;
+
+        let imported = match args.format {
+            ImportFormat::Csv => NameDatabase::from_csv(text.as_bytes()),
+            ImportFormat::Filelist => Ok(NameDatabase::from_filelist(&text)),
+            ImportFormat::Table => NameDatabase::from_table(&text),
+        }.map_err(|err| format!("Couldn't parse '{}': {}", path.display(), err))?;
+
+        for (ktid, name) in imported.iter() {
+            names.insert(ktid, name.to_string());
         }
+    }
 
-        for typeinfo in &typeinfos {
-            let mut path = PathBuf::from(format!(".\\src\\{}", &typeinfo.replace("::", "\\")));
+    update_dictionary(&dictionary_path(args.dictionary.as_deref())?, &names)
+}
 
-            path.ancestors().for_each(|ancestor| {
-                let dirs: Vec<String> = std::fs::read_dir(ancestor).unwrap().filter_map(|dir| {
-                    let dir = dir.unwrap();
-                    if dir.path().is_dir() {
-                        Some(dir.file_name().to_str().unwrap().to_string())
-                    } else {
-                        None
-                    }
-                }).collect();
+/// Returns the amount of problems found
+fn verify_rdb(args: &Verify) -> Result<usize, String> {
+    let problems = rdb_tool::verify::verify_file(&args.path).map_err(|err| format!("Couldn't parse the RDB: {}", err))?;
+
+    for problem in &problems {
+        println!("{}", problem);
+    }
+
+    if problems.is_empty() {
+        println!("No problems found in {}", args.path.display());
+    } else {
+        println!("{} problems found in {}", problems.len(), args.path.display());
+    }
 
-                let mod_path = ancestor.join("mod.rs");
+    Ok(problems.len())
+}
 
-                if !mod_path.exists() {
-                    let mut output = String::new();
+fn diff_rdb(args: &Diff) -> Result<(), String> {
+    let old = Rdb::open(&args.old_path).map_err(|err| format!("Couldn't parse '{}': {}", args.old_path.display(), err))?;
+    let new = Rdb::open(&args.new_path).map_err(|err| format!("Couldn't parse '{}': {}", args.new_path.display(), err))?;
 
-                for dir in dirs {
-                    output.push_str(&format!("pub mod {};\n", dir));
-                }
+    let diff = rdb_tool::diff::diff(&old, &new);
 
-                std::fs::write(&mod_path, &output);
-                }
-            });
+    if args.json {
+        let json = serde_json::to_string_pretty(&diff).map_err(|err| err.to_string())?;
+        println!("{}", json);
+        return Ok(());
+    }
+
+    for entry in &diff.added {
+        println!("+ 0x{:08x} (entry type {}, size 0x{:x}) {}", entry.file_ktid, entry.entry_type, entry.file_size, entry.name);
+    }
+
+    for entry in &diff.removed {
+        println!("- 0x{:08x} (entry type {}, size 0x{:x}) {}", entry.file_ktid, entry.entry_type, entry.file_size, entry.name);
+    }
+
+    for entry in &diff.modified {
+        println!("~ 0x{:08x}", entry.file_ktid);
+
+        for change in &entry.changes {
+            println!("    {}: {} -> {}", change.field, change.old, change.new);
         }
+    }
+
+    println!("{} added, {} removed, {} modified", diff.added.len(), diff.removed.len(), diff.modified.len());
+
+    Ok(())
+}
+
+fn list_entries(args: &List) -> Result<(), String> {
+    let rdb = Rdb::open(&args.path).map_err(|err| format!("Couldn't open the RDB: {}", err))?;
+
+    let type_info_ktids = args.type_info.iter().map(|type_info| parse_type_info(type_info)).collect::<Result<_, _>>()?;
+
+    let type_under = args.type_under.iter().map(|type_info| parse_type_path(type_info)).collect::<Result<_, _>>()?;
+
+    let filter = EntryFilter {
+        type_info_ktids,
+        type_under,
+        entry_types: args.entry_type.clone(),
+        external: args.external,
+        internal: args.internal,
+        compressed: args.compressed,
+        min_size: args.min_size,
+        max_size: args.max_size,
+    };
+
+    let names = load_names(&rdb, &args.path, args.names.as_deref())?;
+
+    let rows: Vec<ListedEntry> = rdb.entries.iter().filter(|entry| filter.matches(entry)).map(|entry| {
+        let row = ListedEntry::from(entry);
+
+        ListedEntry {
+            type_name: row.type_name.or_else(|| names.get(entry.type_info_ktid).map(str::to_string)),
+            file_name: names.get(entry.file_ktid).map(str::to_string),
+            ..row
+        }
+    }).collect();
+
+    match args.format {
+        OutputFormat::Table => {
+            let type_names: Vec<String> = rows.iter().map(|row| row.type_name.clone().unwrap_or_else(|| format!("0x{:08x}", row.type_info))).collect();
+            let width = type_names.iter().map(String::len).max().unwrap_or(0).max("TypeInfo".len());
+
+            let name_width = rows.iter().map(|row| row.name.len()).max().unwrap_or(0).max("Name".len());
+
+            let resolver = (typeinfo::Registry, &names);
+            let ktids: Vec<String> = rows.iter().map(|row| row.ktid.display(args.ktid_format, Some(&resolver)).to_string()).collect();
+            let ktid_width = ktids.iter().map(|ktid| ktid.chars().count()).max().unwrap_or(0).max("KTID".len());
+
+            println!("{:<ktid_width$}  {:<width$}  {:<4}  {:<8}  {:<12}  {:>10}  {:<name_width$}  File", "KTID", "TypeInfo", "Type", "Location", "Storage", "Size", "Name", ktid_width = ktid_width, width = width, name_width = name_width);
+
+            for ((row, type_name), ktid) in rows.iter().zip(&type_names).zip(&ktids) {
+                println!("{:<ktid_width$}  {:<width$}  {:<4}  {:<8}  {:<12}  {:>10}  {:<name_width$}  {}", ktid, type_name, row.entry_type, row.location, row.storage, format!("0x{:x}", row.size), row.name, row.file_name.as_deref().unwrap_or(""), ktid_width = ktid_width, width = width, name_width = name_width);
+            }
+
+            println!("{} entries", rows.len());
+        },
+        OutputFormat::Json => {
+            let json = serde_json::to_string_pretty(&rows).map_err(|err| err.to_string())?;
+            println!("{}", json);
+        },
+        OutputFormat::Csv => {
+            let mut writer = csv::Writer::from_writer(std::io::stdout());
+
+            for row in &rows {
+                writer.serialize(row).map_err(|err| err.to_string())?;
+            }
+
+            writer.flush().map_err(|err| err.to_string())?;
+        },
+    }
+
+    Ok(())
+}
+
+fn generate_typeinfos(args: &GenerateTypeinfos) -> Result<(), String> {
+    let mut records = vec![];
 
-        println!("{:x}", ktid::ktid("TypeInfo::Object::3D::Displayset::TrianglesEx").as_u32())
+    for path in &args.csv {
+        records.extend(rdb_tool::codegen::read_csv(path).map_err(|err| format!("Couldn't read '{}': {}", path.display(), err))?);
+    }
+
+    let generated = rdb_tool::codegen::generate(&records, &args.out_dir).map_err(|err| format!("Couldn't generate the modules: {}", err))?;
+
+    for record in &generated.conflicts {
+        println!("Skipped {} ({}), another type uses the same module", record.typename, record.typekind);
+    }
+
+    println!("Wrote {} files in {}", generated.files.len(), args.out_dir.display());
+
+    Ok(())
+}
+
+fn main() {
+    let opt = Opt::from_args_safe().unwrap_or_else(|err| {
+        // Help and version go to stdout and exit successfully
+        if !err.use_stderr() {
+            err.exit();
+        }
+
+        eprintln!("{}", err);
+        std::process::exit(2);
+    });
+
+    let result = match opt.cmd {
+        Command::Patch(args) => patch_rdb(&args),
+        Command::Print(args) => print_entry(&args),
+        Command::Extract(args) => extract_rdb(&args),
+        Command::Add(args) => add_entries(&args),
+        Command::Remove(args) => remove_entries(&args),
+        Command::Disable(args) => disable_entries(&args),
+        Command::Verify(args) => match verify_rdb(&args) {
+            Ok(0) => Ok(()),
+            // The problems are already reported, only the exit code is left
+            Ok(_) => std::process::exit(1),
+            Err(error_msg) => Err(error_msg),
+        },
+        Command::Diff(args) => diff_rdb(&args),
+        Command::List(args) => list_entries(&args),
+        Command::GenerateTypeinfos(args) => generate_typeinfos(&args),
+        Command::Crack(args) => crack_names(&args),
+        Command::Hash(args) => hash_strings(&args),
+        Command::Names(cmd) => match cmd {
+            NamesCommand::Add(args) => names_add(&args),
+            NamesCommand::Import(args) => names_import(&args),
+        },
+        Command::Idrk(cmd) => match cmd {
+            IdrkCommand::Wrap(args) => idrk_wrap(&args),
+            IdrkCommand::Unwrap(args) => idrk_unwrap(&args),
+        },
+        Command::Objdb(cmd) => match cmd {
+            ObjdbCommand::Dump(args) => objdb_dump(&args),
+        },
+    };
+
+    // Batch scripts rely on the exit code to notice failures
+    if let Err(error_msg) = result {
+        eprintln!("{}", error_msg);
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    //const TEST_CONTENTS: &[u8] = include_bytes!("../system.rdb");
+
+    #[test]
+    fn test() {
+        let _test = typeinfo::object::sound::bank::ID;
+    }
+
+    #[test]
+    fn type_info_names_hash_to_their_id() {
+        assert_eq!(ktid::ktid("TypeInfo::Object::Sound::Bank").unwrap(), typeinfo::object::sound::bank::ID);
+    }
+
+    #[test]
+    fn guess_extensions() {
+        assert_eq!(guess_extension(b"GT1G0600"), "g1t");
+        assert_eq!(guess_extension(b"_M1G0037"), "g1m");
+        assert_eq!(guess_extension(b"\0\0"), "file");
+
+        assert_eq!(guess_entry_type(b"GT1G0600"), 8);
+        assert_eq!(guess_entry_type(&Idrk::new(12, ktid::KTID(1), ktid::KTID(0), StorageMode::Uncompressed, b"GT1G0600").unwrap().to_bytes().unwrap()), 12);
+    }
+
+    #[test]
+    fn hash_schemes() {
+        assert_eq!(HashScheme::Raw.hashed_string("chr0001"), Ok("chr0001".to_string()));
+        assert!(HashScheme::Raw.hashed_string("").is_err());
+        assert_eq!(HashScheme::Path.hashed_string("dir/chr0001.g1t"), Ok("R_G1T［chr0001］".to_string()));
+        assert!(HashScheme::Path.hashed_string("chr0001").is_err());
+        assert_eq!(HashScheme::TypeInfo.hashed_string("object::sound::bank"), Ok("TypeInfo::Object::Sound::Bank".to_string()));
+        assert_eq!(HashScheme::TypeInfo.hashed_string("Object::Unknown"), Ok("TypeInfo::Object::Unknown".to_string()));
+    }
+
+    #[test]
+    fn ranges() {
+        assert_eq!(parse_range("0-9999"), Ok(0..=9999));
+        assert_eq!(parse_range("5 - 5"), Ok(5..=5));
+        assert!(parse_range("10-1").is_err());
+        assert!(parse_range("10").is_err());
+    }
+
+    #[test]
+    fn named_files_patch_back_to_their_ktid() {
+        assert_eq!(sanitize_filename("chr/chr0001:a"), "chr_chr0001_a");
+        assert_eq!(ktid::ktid_from_filename(Path::new("0x12345678.chr_chr0001.g1t")).unwrap(), ktid::KTID(0x12345678));
+        assert_eq!(ktid::ktid_from_filename(Path::new("0x12345678.g1t")).unwrap(), ktid::KTID(0x12345678));
     }
 
     // #[test]
@@ -222,13 +1244,99 @@ mod tests {
     //     dbg!(entry);
     // }
 
+    /// RDB written through the library, the synthetic builders of its tests aren't part of its API
+    fn write_rdb(path: &Path, ktids: &[u32]) {
+        use rdb_tool::rdb::{RdbHeader, RDB_MAGIC, RDB_VERSION};
+
+        let header = RdbHeader { magic: RDB_MAGIC, version: RDB_VERSION, header_size: 0, system_id: 0, file_count: 0, ktid: ktid::KTID(0x1234), path: "data".to_string(), padding: vec![0; 3] };
+        let mut rdb = Rdb { header, entries: vec![] };
+
+        for ktid in ktids {
+            rdb.add_entry(RdbEntry::new(ktid::KTID(*ktid), ktid::KTID(0), 8).unwrap()).unwrap().make_external();
+        }
+
+        rdb.save(path).unwrap();
+    }
+
+    #[test]
+    fn patch_internal() {
+        let dir = tempfile::tempdir().unwrap();
+        let rdb_path = dir.path().join("test.rdb");
+        let out_path = dir.path().join("out.rdb");
+
+        write_rdb(&rdb_path, &[0x11111111, 0x22222222]);
+        std::fs::create_dir(dir.path().join("patch")).unwrap();
+        std::fs::write(dir.path().join("patch").join("0x11111111.g1t"), b"GT1G patched").unwrap();
+
+        patch_rdb(&Patch { path: rdb_path, out_path: out_path.clone(), data_path: PathBuf::from("patch"), compress: Some(Compression::Zlib), allow_encrypted: false, fdata: Some("mod.fdata".to_string()) }).unwrap();
+
+        let rdb = Rdb::open(&out_path).unwrap();
+        let entry = rdb.get_entry_by_ktid(ktid::ktid("0x11111111").unwrap()).unwrap();
+
+        assert_eq!(entry.get_name(), "mod.fdata@10@c");
+        assert_eq!(entry.storage_mode(), StorageMode::Zlib);
+        assert_eq!(rdb.read_entry(entry, dir.path()).unwrap(), b"GT1G patched");
+        // The other entry is untouched
+        assert_eq!(rdb.get_entry_by_ktid(ktid::ktid("0x22222222").unwrap()).unwrap().get_name(), "0x22222222.file@0");
+    }
+
+    #[test]
+    fn patch_internal_from_idrk() {
+        let dir = tempfile::tempdir().unwrap();
+        let rdb_path = dir.path().join("test.rdb");
+        let out_path = dir.path().join("out.rdb");
+
+        write_rdb(&rdb_path, &[0x11111111]);
+        std::fs::create_dir(dir.path().join("patch")).unwrap();
+
+        // As found in the data directory of an external entry
+        let idrk = Idrk::new(8, ktid::KTID(0x11111111), ktid::KTID(0), StorageMode::Lz4, b"GT1G already wrapped").unwrap();
+        std::fs::write(dir.path().join("patch").join("0x11111111.file"), idrk.to_bytes().unwrap()).unwrap();
+
+        patch_rdb(&Patch { path: rdb_path.clone(), out_path: out_path.clone(), data_path: PathBuf::from("patch"), compress: None, allow_encrypted: false, fdata: Some("mod.fdata".to_string()) }).unwrap();
+
+        let rdb = Rdb::open(&out_path).unwrap();
+        let entry = &rdb.entries[0];
+
+        assert_eq!(entry.storage_mode(), StorageMode::Lz4);
+        assert_eq!(entry.file_size, 20);
+        assert_eq!(entry.get_name(), "mod.fdata@10@14");
+        assert_eq!(rdb.read_entry(entry, dir.path()).unwrap(), b"GT1G already wrapped");
+
+        // Blobs of other entries are refused
+        std::fs::rename(dir.path().join("patch").join("0x11111111.file"), dir.path().join("patch").join("0x22222222.file")).unwrap();
+        write_rdb(&rdb_path, &[0x22222222]);
+        assert!(patch_rdb(&Patch { path: rdb_path, out_path, data_path: PathBuf::from("patch"), compress: None, allow_encrypted: false, fdata: Some("mod.fdata".to_string()) }).is_err());
+    }
+
+    #[test]
+    fn extract_reports_failures() {
+        let dir = tempfile::tempdir().unwrap();
+        let rdb_path = dir.path().join("test.rdb");
+
+        // External entries whose file is missing
+        write_rdb(&rdb_path, &[0x11111111, 0x22222222]);
+
+        let extract = |ktid: Vec<String>| extract_rdb(&Extract { path: rdb_path.clone(), out_dir: dir.path().join("out"), ktid, names: None });
+        assert_eq!(extract(vec![]), Err("Couldn't extract 2 entries".to_string()));
+        assert_eq!(extract(vec!["0x11111111".to_string()]), Err("Couldn't extract 1 entries".to_string()));
+    }
+
     #[test]
-    fn patch_texternal() {
-        //let mut rdb: Rdb = Rdb::read(&mut Cursor::new(TEST_CONTENTS)).unwrap();
-        patch_rdb(&Opt { path: PathBuf::from("RRPreview.rdb"), out_path: PathBuf::from("RRPreview.rdb"), data_path: PathBuf::from("data") });
-        //patch_rdb(Path::new("KIDSSystemResource.rdb"), Path::new("cock.rdb"));
-        // let entry = rdb.get_entry_by_KTID(0x0a696242).unwrap();
-        // entry.patch_external_file();
-        //dbg!(entry);
+    fn patch_external() {
+        let dir = tempfile::tempdir().unwrap();
+        let rdb_path = dir.path().join("test.rdb");
+        let out_path = dir.path().join("out").join("out.rdb");
+
+        write_rdb(&rdb_path, &[0x11111111]);
+        std::fs::create_dir_all(dir.path().join("patch")).unwrap();
+        std::fs::create_dir_all(dir.path().join("out")).unwrap();
+        std::fs::write(dir.path().join("patch").join("0x11111111.g1t"), b"GT1G patched").unwrap();
+
+        patch_rdb(&Patch { path: rdb_path, out_path: out_path.clone(), data_path: PathBuf::from("patch"), compress: Some(Compression::Lz4), allow_encrypted: false, fdata: None }).unwrap();
+
+        // The external file goes next to the output RDB, wherever the tool is run from
+        let rdb = Rdb::open(&out_path).unwrap();
+        assert_eq!(rdb.read_entry(&rdb.entries[0], dir.path().join("out")).unwrap(), b"GT1G patched");
     }
 }
@@ -0,0 +1,393 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use binread::{io::Cursor, BinRead, BinReaderExt, NullString};
+
+use crate::error::{Error, Result};
+use crate::ktid::{ktid_hash, parse_number, KtidResolver, KTID};
+use crate::rdb::Rdb;
+use crate::typeinfo::resource::system::namedatabasefile;
+
+pub const NAME_DB_MAGIC: u32 = u32::from_le_bytes(*b"_NDB");
+pub const NAME_DB_VERSION: u32 = 0x30303030;
+pub const NAME_DB_HEADER_SIZE: u32 = 0x10;
+/// Extension of the name databases looked up next to a RDB
+pub const NAME_DB_EXTENSION: &str = "ndb";
+/// Name of the dictionaries, CSV files of `0x????????,name` lines without headers, looked up next to a RDB and in the configuration directory
+pub const DICTIONARY_NAME: &str = "names.csv";
+
+/// Magic, version, header size and entry count, like the header of a .fdata container.
+/// There is no public specification of this format: the layout is reconstructed after the other KTGL containers and hasn't been checked
+/// against a name database shipped with a game yet. The `shipped_name_databases` test does so when `RDB_TOOL_SAMPLES` points to a directory of `.ndb` files.
+#[derive(BinRead, Debug)]
+pub struct NameDatabaseHeader {
+    #[br(assert(magic == NAME_DB_MAGIC, Error::BadMagic { expected: NAME_DB_MAGIC, found: magic }))]
+    pub magic: u32,
+    #[br(assert(version == NAME_DB_VERSION, Error::BadVersion(version)))]
+    pub version: u32,
+    pub header_size: u32,
+    pub entry_count: u32,
+}
+
+/// Follows the header. The offset of the null-terminated name is relative to the start of the file.
+#[derive(BinRead, Debug)]
+pub struct NameDatabaseEntry {
+    pub ktid: u32,
+    pub name_offset: u32,
+}
+
+/// Names of the files of a game (`TypeInfo::Resource::System::NameDatabaseFile`), keyed by KTID
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct NameDatabase {
+    names: BTreeMap<KTID, String>,
+}
+
+impl NameDatabase {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Dictionaries are recognized by their `.csv` extension, anything else is parsed as a name database
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+
+        match is_dictionary(path) {
+            true => Self::from_csv(std::fs::File::open(path)?),
+            false => Self::from_bytes(&std::fs::read(path)?),
+        }
+    }
+
+    /// Write the names as a dictionary or a name database depending on the extension, like `open`
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+
+        if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        match is_dictionary(path) {
+            true => std::fs::write(path, self.to_csv()?)?,
+            false => std::fs::write(path, self.to_bytes())?,
+        }
+
+        Ok(())
+    }
+
+    /// `ktid,name` lines without headers, the KTID being `0x` hex or decimal
+    pub fn from_csv<R: std::io::Read>(reader: R) -> Result<Self> {
+        let mut reader = csv::ReaderBuilder::new().has_headers(false).from_reader(reader);
+        let mut names = BTreeMap::new();
+
+        for record in reader.deserialize() {
+            let (ktid, name): (String, String) = record?;
+            names.insert(KTID(parse_number(&ktid).ok_or(Error::InvalidKtid(ktid))?), name);
+        }
+
+        Ok(Self { names })
+    }
+
+    pub fn to_csv(&self) -> Result<Vec<u8>> {
+        let mut writer = csv::WriterBuilder::new().has_headers(false).from_writer(vec![]);
+
+        for (ktid, name) in &self.names {
+            writer.serialize((ktid, name))?;
+        }
+
+        writer.into_inner().map_err(|err| Error::Io(err.into_error()))
+    }
+
+    /// List of names, one per line, like the filelists of Cethleann. The KTIDs are the hashes of the lines.
+    pub fn from_filelist(text: &str) -> Self {
+        let names = text.lines().map(str::trim).filter(|line| !line.is_empty()).map(|line| (KTID(ktid_hash(line, 31)), line.to_string())).collect();
+
+        Self { names }
+    }
+
+    /// Hash conversion table, one `name = ktid` pair per line. The separator can be `=`, `,`, a tab or spaces, and the KTID can come first.
+    /// Empty lines and lines starting with `#` are ignored.
+    pub fn from_table(text: &str) -> Result<Self> {
+        let mut names = BTreeMap::new();
+
+        for line in text.lines().map(str::trim).filter(|line| !line.is_empty() && !line.starts_with('#')) {
+            let (first, second) = line.split_once(['=', ',', '\t'])
+                .or_else(|| line.split_once(char::is_whitespace))
+                .ok_or_else(|| Error::InvalidKtid(line.to_string()))?;
+            let (first, second) = (first.trim(), second.trim());
+
+            let (ktid, name) = match (parse_number(first), parse_number(second)) {
+                (_, Some(ktid)) => (ktid, first),
+                (Some(ktid), None) => (ktid, second),
+                (None, None) => return Err(Error::InvalidKtid(line.to_string())),
+            };
+
+            names.insert(KTID(ktid), name.to_string());
+        }
+
+        Ok(Self { names })
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut reader = Cursor::new(bytes);
+        let header: NameDatabaseHeader = reader.read_le()?;
+
+        reader.set_position(header.header_size as u64);
+
+        let entries: Vec<NameDatabaseEntry> = (0..header.entry_count).map(|_| reader.read_le()).collect::<binread::BinResult<_>>()?;
+
+        let mut names = BTreeMap::new();
+
+        for entry in entries {
+            reader.set_position(entry.name_offset as u64);
+            let name: NullString = reader.read_le()?;
+            names.insert(KTID(entry.ktid), String::from_utf8_lossy(&name.0).into_owned());
+        }
+
+        Ok(Self { names })
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+
+        bytes.extend_from_slice(&NAME_DB_MAGIC.to_le_bytes());
+        bytes.extend_from_slice(&NAME_DB_VERSION.to_le_bytes());
+        bytes.extend_from_slice(&NAME_DB_HEADER_SIZE.to_le_bytes());
+        bytes.extend_from_slice(&(self.names.len() as u32).to_le_bytes());
+
+        let mut name_offset = NAME_DB_HEADER_SIZE as usize + self.names.len() * 8;
+
+        for (ktid, name) in &self.names {
+            bytes.extend_from_slice(&ktid.as_u32().to_le_bytes());
+            bytes.extend_from_slice(&(name_offset as u32).to_le_bytes());
+            name_offset += name.len() + 1;
+        }
+
+        for name in self.names.values() {
+            bytes.extend_from_slice(name.as_bytes());
+            bytes.push(0);
+        }
+
+        bytes
+    }
+
+    pub fn get(&self, ktid: KTID) -> Option<&str> {
+        self.names.get(&ktid).map(String::as_str)
+    }
+
+    /// Returns the previous name of the KTID
+    pub fn insert(&mut self, ktid: KTID, name: String) -> Option<String> {
+        self.names.insert(ktid, name)
+    }
+
+    /// Add the names of another database, keeping the current ones when both have a name for the same KTID
+    pub fn merge(&mut self, other: NameDatabase) {
+        for (ktid, name) in other.names {
+            self.names.entry(ktid).or_insert(name);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Every (KTID, name), sorted by KTID
+    pub fn iter(&self) -> impl Iterator<Item = (KTID, &str)> {
+        self.names.iter().map(|(ktid, name)| (*ktid, name.as_str()))
+    }
+
+    /// Names available for a RDB: the `<rdb name>.ndb` file and the `names.csv` dictionary next to it, then the name databases stored as entries of the RDB.
+    /// Unreadable entries are skipped, as most RDBs don't ship every file. A `.ndb` file that can't be parsed is skipped too, as the layout isn't verified,
+    /// and returned along with the error so it can be reported.
+    pub fn find_for_rdb<P: AsRef<Path>>(rdb: &Rdb, rdb_path: P) -> Result<(Self, Option<(PathBuf, Error)>)> {
+        let rdb_path = rdb_path.as_ref();
+        let rdb_dir = rdb_path.parent().unwrap_or_else(|| Path::new(""));
+
+        let mut names = NameDatabase::new();
+        let mut skipped = None;
+
+        let sibling = rdb_path.with_extension(NAME_DB_EXTENSION);
+        if sibling.exists() {
+            match NameDatabase::open(&sibling) {
+                Ok(database) => names.merge(database),
+                Err(err) => skipped = Some((sibling, err)),
+            }
+        }
+
+        let dictionary = rdb_dir.join(DICTIONARY_NAME);
+        if dictionary.exists() {
+            names.merge(NameDatabase::open(dictionary)?);
+        }
+
+        for entry in rdb.entries_of::<namedatabasefile::Type>() {
+            if let Some(database) = rdb.read_entry(entry, rdb_dir).ok().and_then(|data| NameDatabase::from_bytes(&data).ok()) {
+                names.merge(database);
+            }
+        }
+
+        Ok((names, skipped))
+    }
+}
+
+impl KtidResolver for NameDatabase {
+    fn resolve(&self, ktid: KTID) -> Option<&str> {
+        self.get(ktid)
+    }
+}
+
+fn is_dictionary(path: &Path) -> bool {
+    path.extension().is_some_and(|extension| extension.eq_ignore_ascii_case("csv"))
+}
+
+/// The dictionary shared by every RDB: `names.csv` in `$RDB_TOOL_CONFIG_DIR`, or in a `rdb_tool` directory under `$XDG_CONFIG_HOME`, `%APPDATA%` or `~/.config`
+pub fn dictionary_path() -> Option<PathBuf> {
+    let dir = match std::env::var_os("RDB_TOOL_CONFIG_DIR") {
+        Some(dir) => PathBuf::from(dir),
+        None => std::env::var_os("XDG_CONFIG_HOME").map(PathBuf::from)
+            .or_else(|| std::env::var_os("APPDATA").map(PathBuf::from))
+            .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?
+            .join("rdb_tool"),
+    };
+
+    Some(dir.join(DICTIONARY_NAME))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mut names = NameDatabase::new();
+        names.insert(KTID(0x22222222), "chr0002".to_string());
+        names.insert(KTID(0x11111111), "chr0001".to_string());
+        names.insert(KTID(0x33333333), String::new());
+
+        let bytes = names.to_bytes();
+        assert_eq!(&bytes[..4], b"_NDB");
+
+        let parsed = NameDatabase::from_bytes(&bytes).unwrap();
+        assert_eq!(parsed, names);
+        assert_eq!(parsed.get(KTID(0x11111111)), Some("chr0001"));
+        assert_eq!(parsed.get(KTID(0x44444444)), None);
+        assert_eq!(parsed.iter().map(|(ktid, _)| ktid.as_u32()).collect::<Vec<u32>>(), [0x11111111, 0x22222222, 0x33333333]);
+
+        assert!(matches!(NameDatabase::from_bytes(b"_DRK0000"), Err(Error::BadMagic { .. })));
+    }
+
+    #[test]
+    fn shipped_name_databases() {
+        for (path, bytes) in crate::samples::files(NAME_DB_EXTENSION) {
+            let names = NameDatabase::from_bytes(&bytes).unwrap_or_else(|err| panic!("{}: {}", path.display(), err));
+
+            assert!(!names.is_empty(), "{} has no names", path.display());
+            assert!(names.iter().all(|(_, name)| !name.is_empty() && !name.contains('\u{fffd}')), "{} has garbage names", path.display());
+            assert_eq!(NameDatabase::from_bytes(&names.to_bytes()).unwrap(), names);
+        }
+    }
+
+    #[test]
+    fn resolves_ktids() {
+        use crate::ktid::KtidFormat;
+        use crate::typeinfo::{object::sound::bank, Registry};
+
+        let mut names = NameDatabase::new();
+        names.insert(KTID(0xbeef), "chr0001".to_string());
+        names.insert(bank::ID, "ignored".to_string());
+
+        let resolver = (Registry, &names);
+        assert_eq!(KTID(0xbeef).display(KtidFormat::Name, Some(&resolver)).to_string(), "chr0001");
+        assert_eq!(bank::ID.display(KtidFormat::Name, Some(&resolver)).to_string(), "TypeInfo::Object::Sound::Bank");
+        assert_eq!(bank::ID.display(KtidFormat::Name, Some(&names)).to_string(), "ignored");
+    }
+
+    #[test]
+    fn merge_keeps_existing_names() {
+        let mut names = NameDatabase::new();
+        names.insert(KTID(1), "first".to_string());
+
+        let mut other = NameDatabase::new();
+        other.insert(KTID(1), "second".to_string());
+        other.insert(KTID(2), "other".to_string());
+
+        names.merge(other);
+        assert_eq!(names.get(KTID(1)), Some("first"));
+        assert_eq!(names.get(KTID(2)), Some("other"));
+    }
+
+    #[test]
+    fn found_next_to_the_rdb() {
+        use crate::rdb::testing::*;
+
+        let dir = tempfile::tempdir().unwrap();
+        let rdb_path = dir.path().join("system.rdb");
+        let rdb = Rdb::from_bytes(&synthetic_rdb("data", &[synthetic_entry(1, 0, "", 1 << 16)])).unwrap();
+
+        assert!(NameDatabase::find_for_rdb(&rdb, &rdb_path).unwrap().0.is_empty());
+
+        // Name databases that can't be parsed are skipped
+        std::fs::write(dir.path().join("system.ndb"), b"garbage").unwrap();
+        let (found, skipped) = NameDatabase::find_for_rdb(&rdb, &rdb_path).unwrap();
+        assert!(found.is_empty());
+        assert!(matches!(skipped, Some((path, Error::BadMagic { .. })) if path == dir.path().join("system.ndb")));
+
+        let mut names = NameDatabase::new();
+        names.insert(KTID(1), "chr0001".to_string());
+        std::fs::write(dir.path().join("system.ndb"), names.to_bytes()).unwrap();
+
+        assert_eq!(NameDatabase::find_for_rdb(&rdb, &rdb_path).unwrap().0, names);
+
+        let mut dictionary = NameDatabase::new();
+        dictionary.insert(KTID(1), "ignored".to_string());
+        dictionary.insert(KTID(4), "chr0004".to_string());
+        dictionary.save(dir.path().join(DICTIONARY_NAME)).unwrap();
+
+        assert_eq!(NameDatabase::find_for_rdb(&rdb, &rdb_path).unwrap().0.get(KTID(4)), Some("chr0004"));
+
+        // Name databases stored in the RDB are used too
+        let mut rdb = rdb;
+        let mut entry = crate::rdb::RdbEntry::new(KTID(2), namedatabasefile::ID, 0).unwrap();
+        let mut stored = NameDatabase::new();
+        stored.insert(KTID(1), "ignored".to_string());
+        stored.insert(KTID(3), "chr0003".to_string());
+
+        entry.file_size = stored.to_bytes().len() as u64;
+        entry.make_external();
+        std::fs::create_dir(dir.path().join("data")).unwrap();
+        std::fs::write(dir.path().join("data").join("0x00000002.file"), crate::idrk::Idrk::from_entry(&entry, &stored.to_bytes()).unwrap().to_bytes().unwrap()).unwrap();
+        rdb.add_entry(entry);
+
+        let (found, _) = NameDatabase::find_for_rdb(&rdb, &rdb_path).unwrap();
+        assert_eq!(found.get(KTID(1)), Some("chr0001"));
+        assert_eq!(found.get(KTID(3)), Some("chr0003"));
+    }
+
+    #[test]
+    fn dictionaries() {
+        let mut names = NameDatabase::new();
+        names.insert(KTID(0x11111111), "chr0001".to_string());
+        names.insert(KTID(0x22222222), "with, comma".to_string());
+
+        let csv = names.to_csv().unwrap();
+        assert!(String::from_utf8_lossy(&csv).starts_with("0x11111111,chr0001\n"));
+        assert_eq!(NameDatabase::from_csv(&csv[..]).unwrap(), names);
+        assert_eq!(NameDatabase::from_csv(&b"1,one\n0x10,sixteen\n"[..]).unwrap().iter().collect::<Vec<_>>(), [(KTID(1), "one"), (KTID(0x10), "sixteen")]);
+        assert!(matches!(NameDatabase::from_csv(&b"chr0001,chr0001\n"[..]), Err(Error::InvalidKtid(_))));
+
+        let dir = tempfile::tempdir().unwrap();
+        names.save(dir.path().join("names.csv")).unwrap();
+        names.save(dir.path().join("names.ndb")).unwrap();
+        assert_eq!(NameDatabase::open(dir.path().join("names.csv")).unwrap(), names);
+        assert_eq!(NameDatabase::open(dir.path().join("names.ndb")).unwrap(), names);
+
+        let filelist = NameDatabase::from_filelist("chr0001\n\n  R_G1T［chr0002］\n");
+        assert_eq!(filelist.get(crate::ktid::ktid("chr0001").unwrap()), Some("chr0001"));
+        assert_eq!(filelist.get(crate::ktid::KTID::new("chr0002.g1t").unwrap()), Some("R_G1T［chr0002］"));
+
+        let table = NameDatabase::from_table("# name = ktid\nchr0001 = 0x11111111\n0x22222222\tchr0002\nchr0003 0x33333333\n").unwrap();
+        assert_eq!(table.iter().collect::<Vec<_>>(), [(KTID(0x11111111), "chr0001"), (KTID(0x22222222), "chr0002"), (KTID(0x33333333), "chr0003")]);
+        assert!(NameDatabase::from_table("chr0001").is_err());
+    }
+}
@@ -0,0 +1,413 @@
+//! KIDS object databases (`TypeInfo::Resource::System::ObjectDatabaseFile`), holding the placeables, params and settings of a game.
+//!
+//! Layout, little endian:
+//! - header: magic `KIDS`, version `0000`, total size, object count, KTID of the database
+//! - every object: size (including this header and the padding), KTID, TypeInfo KTID, property count,
+//!   followed by a (type, count, KTID) triplet per property and then the values of every property, each aligned on 4 bytes
+//!
+//! Experimental: there is no public specification of this format. The layout and the property type codes of [`PropertyValue::value_type`] are reconstructed
+//! and haven't been checked against an object database shipped with a game yet, so databases are only read. Writing them back is left out until they are.
+//! The `shipped_object_databases` test does the check when `RDB_TOOL_SAMPLES` points to a directory of `.kidsobjdb` files.
+
+use std::path::Path;
+
+use binread::{io::Cursor, BinRead, BinReaderExt, BinResult};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Error, Result};
+use crate::ktid::KTID;
+
+pub const OBJDB_MAGIC: u32 = u32::from_le_bytes(*b"KIDS");
+pub const OBJDB_VERSION: u32 = 0x30303030;
+pub const OBJDB_HEADER_SIZE: u32 = 0x14;
+pub const OBJECT_HEADER_SIZE: u32 = 0x10;
+pub const PROPERTY_INFO_SIZE: u32 = 0xc;
+
+#[derive(BinRead, Debug)]
+pub struct ObjectDatabaseHeader {
+    #[br(assert(magic == OBJDB_MAGIC, Error::BadMagic { expected: OBJDB_MAGIC, found: magic }))]
+    pub magic: u32,
+    #[br(assert(version == OBJDB_VERSION, Error::BadVersion(version)))]
+    pub version: u32,
+    pub size: u32,
+    pub object_count: u32,
+    pub file_ktid: KTID,
+}
+
+#[derive(BinRead, Debug)]
+pub struct ObjectHeader {
+    pub size: u32,
+    pub ktid: KTID,
+    pub type_info_ktid: KTID,
+    pub property_count: u32,
+}
+
+#[derive(BinRead, Debug)]
+pub struct PropertyInfo {
+    pub value_type: u32,
+    /// Amount of values, or length of the string including its null terminator
+    pub count: u32,
+    pub ktid: KTID,
+}
+
+/// Values of a property, tagged with their type in JSON
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "values", rename_all = "lowercase")]
+pub enum PropertyValue {
+    I8(Vec<i8>),
+    U8(Vec<u8>),
+    I16(Vec<i16>),
+    U16(Vec<u16>),
+    I32(Vec<i32>),
+    U32(Vec<u32>),
+    I64(Vec<i64>),
+    U64(Vec<u64>),
+    F32(Vec<f32>),
+    F64(Vec<f64>),
+    Bool(Vec<bool>),
+    Ktid(Vec<KTID>),
+    String(String),
+}
+
+impl PropertyValue {
+    pub fn value_type(&self) -> u32 {
+        match self {
+            PropertyValue::I8(_) => 0,
+            PropertyValue::U8(_) => 1,
+            PropertyValue::I16(_) => 2,
+            PropertyValue::U16(_) => 3,
+            PropertyValue::I32(_) => 4,
+            PropertyValue::U32(_) => 5,
+            PropertyValue::I64(_) => 6,
+            PropertyValue::U64(_) => 7,
+            PropertyValue::F32(_) => 8,
+            PropertyValue::F64(_) => 9,
+            PropertyValue::Bool(_) => 10,
+            PropertyValue::Ktid(_) => 11,
+            PropertyValue::String(_) => 12,
+        }
+    }
+
+    #[cfg(test)]
+    fn count(&self) -> u32 {
+        match self {
+            PropertyValue::I8(values) => values.len() as u32,
+            PropertyValue::U8(values) => values.len() as u32,
+            PropertyValue::I16(values) => values.len() as u32,
+            PropertyValue::U16(values) => values.len() as u32,
+            PropertyValue::I32(values) => values.len() as u32,
+            PropertyValue::U32(values) => values.len() as u32,
+            PropertyValue::Ktid(values) => values.len() as u32,
+            PropertyValue::I64(values) => values.len() as u32,
+            PropertyValue::U64(values) => values.len() as u32,
+            PropertyValue::F32(values) => values.len() as u32,
+            PropertyValue::F64(values) => values.len() as u32,
+            PropertyValue::Bool(values) => values.len() as u32,
+            PropertyValue::String(string) => string.len() as u32 + 1,
+        }
+    }
+
+    /// Size of a single value of the given type
+    fn value_size(value_type: u32) -> Result<u64> {
+        match value_type {
+            0 | 1 | 10 | 12 => Ok(1),
+            2 | 3 => Ok(2),
+            4 | 5 | 8 | 11 => Ok(4),
+            6 | 7 | 9 => Ok(8),
+            value_type => Err(Error::UnknownPropertyType(value_type)),
+        }
+    }
+
+    /// The reader must end with the object, so that values past it are rejected before being allocated
+    fn read(reader: &mut Cursor<&[u8]>, info: &PropertyInfo) -> Result<Self> {
+        fn values<T: BinRead<Args = ()>>(reader: &mut Cursor<&[u8]>, count: u32) -> BinResult<Vec<T>> {
+            (0..count).map(|_| reader.read_le()).collect()
+        }
+
+        let count = info.count;
+        let remaining = reader.get_ref().len() as u64 - reader.position().min(reader.get_ref().len() as u64);
+
+        if count as u64 * Self::value_size(info.value_type)? > remaining {
+            return Err(invalid_data(format!("Property 0x{:08x} at 0x{:x} ends past its object", info.ktid, reader.position())));
+        }
+
+        let value = match info.value_type {
+            0 => PropertyValue::I8(values(reader, count)?),
+            1 => PropertyValue::U8(values(reader, count)?),
+            2 => PropertyValue::I16(values(reader, count)?),
+            3 => PropertyValue::U16(values(reader, count)?),
+            4 => PropertyValue::I32(values(reader, count)?),
+            5 => PropertyValue::U32(values(reader, count)?),
+            6 => PropertyValue::I64(values(reader, count)?),
+            7 => PropertyValue::U64(values(reader, count)?),
+            8 => PropertyValue::F32(values(reader, count)?),
+            9 => PropertyValue::F64(values(reader, count)?),
+            10 => PropertyValue::Bool(values::<u8>(reader, count)?.into_iter().map(|value| value != 0).collect()),
+            11 => PropertyValue::Ktid(values(reader, count)?),
+            12 => {
+                let bytes: Vec<u8> = values(reader, count)?;
+                PropertyValue::String(String::from_utf8_lossy(bytes.split(|byte| *byte == 0).next().unwrap_or_default()).into_owned())
+            },
+            _ => unreachable!("the value size of every type is known"),
+        };
+
+        Ok(value)
+    }
+
+    #[cfg(test)]
+    fn write(&self, bytes: &mut Vec<u8>) {
+        match self {
+            PropertyValue::I8(values) => values.iter().for_each(|value| bytes.extend_from_slice(&value.to_le_bytes())),
+            PropertyValue::U8(values) => bytes.extend_from_slice(values),
+            PropertyValue::I16(values) => values.iter().for_each(|value| bytes.extend_from_slice(&value.to_le_bytes())),
+            PropertyValue::U16(values) => values.iter().for_each(|value| bytes.extend_from_slice(&value.to_le_bytes())),
+            PropertyValue::I32(values) => values.iter().for_each(|value| bytes.extend_from_slice(&value.to_le_bytes())),
+            PropertyValue::U32(values) => values.iter().for_each(|value| bytes.extend_from_slice(&value.to_le_bytes())),
+            PropertyValue::Ktid(values) => values.iter().for_each(|value| bytes.extend_from_slice(&value.as_u32().to_le_bytes())),
+            PropertyValue::I64(values) => values.iter().for_each(|value| bytes.extend_from_slice(&value.to_le_bytes())),
+            PropertyValue::U64(values) => values.iter().for_each(|value| bytes.extend_from_slice(&value.to_le_bytes())),
+            PropertyValue::F32(values) => values.iter().for_each(|value| bytes.extend_from_slice(&value.to_le_bytes())),
+            PropertyValue::F64(values) => values.iter().for_each(|value| bytes.extend_from_slice(&value.to_le_bytes())),
+            PropertyValue::Bool(values) => values.iter().for_each(|value| bytes.push(*value as u8)),
+            PropertyValue::String(string) => {
+                bytes.extend_from_slice(string.as_bytes());
+                bytes.push(0);
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Property {
+    pub ktid: KTID,
+    #[serde(flatten)]
+    pub value: PropertyValue,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Object {
+    pub ktid: KTID,
+    pub type_info: KTID,
+    /// Resolved from the typeinfo registry when reading, only informative when writing
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    pub properties: Vec<Property>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ObjectDatabase {
+    pub file_ktid: KTID,
+    pub objects: Vec<Object>,
+}
+
+fn invalid_data(message: String) -> Error {
+    Error::Io(std::io::Error::new(std::io::ErrorKind::InvalidData, message))
+}
+
+#[cfg(test)]
+fn pad(bytes: &mut Vec<u8>) {
+    bytes.resize(bytes.len().div_ceil(4) * 4, 0);
+}
+
+impl ObjectDatabase {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::from_bytes(&std::fs::read(path)?)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut reader = Cursor::new(bytes);
+        let header: ObjectDatabaseHeader = reader.read_le()?;
+
+        // The count comes from the file, objects are only allocated once they are read
+        let mut objects = Vec::new();
+
+        for _ in 0..header.object_count {
+            let start = reader.position();
+            let object: ObjectHeader = reader.read_le()?;
+
+            let end = start + object.size as u64;
+
+            if object.size < OBJECT_HEADER_SIZE || end > bytes.len() as u64 {
+                return Err(invalid_data(format!("Object at 0x{:x} has an invalid size of 0x{:x}", start, object.size)));
+            }
+
+            if object.property_count as u64 * PROPERTY_INFO_SIZE as u64 > (object.size - OBJECT_HEADER_SIZE) as u64 {
+                return Err(invalid_data(format!("Object at 0x{:x} has more properties than fit in 0x{:x} bytes", start, object.size)));
+            }
+
+            // Properties are read out of the object alone so they can't spill over the next one
+            let mut properties_reader = Cursor::new(&bytes[..end as usize]);
+            properties_reader.set_position(reader.position());
+
+            let infos: Vec<PropertyInfo> = (0..object.property_count).map(|_| properties_reader.read_le()).collect::<BinResult<_>>()?;
+            let mut properties = Vec::with_capacity(infos.len());
+
+            for info in infos {
+                properties.push(Property { ktid: info.ktid, value: PropertyValue::read(&mut properties_reader, &info)? });
+                properties_reader.set_position(properties_reader.position().div_ceil(4) * 4);
+            }
+
+            objects.push(Object {
+                ktid: object.ktid,
+                type_info: object.type_info_ktid,
+                type_name: crate::typeinfo::name(object.type_info_ktid).map(str::to_string),
+                properties,
+            });
+
+            reader.set_position(end);
+        }
+
+        Ok(Self { file_ktid: header.file_ktid, objects })
+    }
+
+    /// Only used to build the fixtures of the tests, as the layout isn't verified
+    #[cfg(test)]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+
+        bytes.extend_from_slice(&OBJDB_MAGIC.to_le_bytes());
+        bytes.extend_from_slice(&OBJDB_VERSION.to_le_bytes());
+        // Total size, filled at the end
+        bytes.extend_from_slice(&0u32.to_le_bytes());
+        bytes.extend_from_slice(&(self.objects.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&self.file_ktid.as_u32().to_le_bytes());
+
+        for object in &self.objects {
+            let mut body = Vec::new();
+
+            for property in &object.properties {
+                body.extend_from_slice(&property.value.value_type().to_le_bytes());
+                body.extend_from_slice(&property.value.count().to_le_bytes());
+                body.extend_from_slice(&property.ktid.as_u32().to_le_bytes());
+            }
+
+            for property in &object.properties {
+                property.value.write(&mut body);
+                pad(&mut body);
+            }
+
+            let size = OBJECT_HEADER_SIZE + body.len() as u32;
+
+            bytes.extend_from_slice(&size.to_le_bytes());
+            bytes.extend_from_slice(&object.ktid.as_u32().to_le_bytes());
+            bytes.extend_from_slice(&object.type_info.as_u32().to_le_bytes());
+            bytes.extend_from_slice(&(object.properties.len() as u32).to_le_bytes());
+            bytes.extend(body);
+        }
+
+        let size = bytes.len() as u32;
+        bytes[8..12].copy_from_slice(&size.to_le_bytes());
+
+        bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::typeinfo::object::sound::bank;
+
+    fn database() -> ObjectDatabase {
+        ObjectDatabase {
+            file_ktid: KTID(0x12345678),
+            objects: vec![
+                Object {
+                    ktid: KTID(1),
+                    type_info: bank::ID,
+                    type_name: Some("TypeInfo::Object::Sound::Bank".to_string()),
+                    properties: vec![
+                        Property { ktid: KTID(0x10), value: PropertyValue::U8(vec![1, 2, 3]) },
+                        Property { ktid: KTID(0x11), value: PropertyValue::F32(vec![0.5, -1.25]) },
+                        Property { ktid: KTID(0x12), value: PropertyValue::String("chr0001".to_string()) },
+                        Property { ktid: KTID(0x13), value: PropertyValue::Bool(vec![true, false]) },
+                        Property { ktid: KTID(0x14), value: PropertyValue::Ktid(vec![KTID(0xdeadbeef)]) },
+                        Property { ktid: KTID(0x15), value: PropertyValue::I64(vec![-1]) },
+                    ],
+                },
+                Object { ktid: KTID(2), type_info: KTID(0xabcdef01), type_name: None, properties: vec![] },
+            ],
+        }
+    }
+
+    #[test]
+    fn binary_round_trip() {
+        let database = database();
+        let bytes = database.to_bytes();
+
+        assert_eq!(&bytes[..4], b"KIDS");
+        assert_eq!(bytes[8..12], (bytes.len() as u32).to_le_bytes());
+
+        let parsed = ObjectDatabase::from_bytes(&bytes).unwrap();
+        assert_eq!(parsed, database);
+        assert_eq!(parsed.to_bytes(), bytes);
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let database = database();
+        let json = serde_json::to_string(&database).unwrap();
+
+        assert!(json.contains(r#"{"ktid":"0x00000014","type":"ktid","values":["0xdeadbeef"]}"#));
+        assert_eq!(serde_json::from_str::<ObjectDatabase>(&json).unwrap(), database);
+    }
+
+    #[test]
+    fn corrupt_sizes() {
+        let mut bytes = database().to_bytes();
+        // Object count
+        bytes[12..16].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(ObjectDatabase::from_bytes(&bytes).is_err());
+
+        // Size of the first object
+        let mut bytes = database().to_bytes();
+        bytes[OBJDB_HEADER_SIZE as usize..OBJDB_HEADER_SIZE as usize + 4].copy_from_slice(&0u32.to_le_bytes());
+        assert!(ObjectDatabase::from_bytes(&bytes).is_err());
+
+        // Property count of the first object
+        let mut bytes = database().to_bytes();
+        bytes[OBJDB_HEADER_SIZE as usize + 12..OBJDB_HEADER_SIZE as usize + 16].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(matches!(ObjectDatabase::from_bytes(&bytes), Err(Error::Io(err)) if err.kind() == std::io::ErrorKind::InvalidData));
+    }
+
+    #[test]
+    fn properties_end_within_their_object() {
+        let database = database();
+        let bytes = database.to_bytes();
+
+        // Value count of the string of the first object, which ends past the object while the second one follows
+        let offset = OBJDB_HEADER_SIZE as usize + OBJECT_HEADER_SIZE as usize + 2 * PROPERTY_INFO_SIZE as usize + 4;
+        let mut corrupt = bytes.clone();
+        corrupt[offset..offset + 4].copy_from_slice(&0x40u32.to_le_bytes());
+        assert!(matches!(ObjectDatabase::from_bytes(&corrupt), Err(Error::Io(err)) if err.kind() == std::io::ErrorKind::InvalidData));
+
+        // Same with a count that would allocate far more than the file
+        corrupt[offset..offset + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(ObjectDatabase::from_bytes(&corrupt).is_err());
+
+        // Size of the first object, shrunk so its last property ends past it
+        let mut corrupt = bytes.clone();
+        let mut size = [0; 4];
+        size.copy_from_slice(&corrupt[OBJDB_HEADER_SIZE as usize..OBJDB_HEADER_SIZE as usize + 4]);
+        corrupt[OBJDB_HEADER_SIZE as usize..OBJDB_HEADER_SIZE as usize + 4].copy_from_slice(&(u32::from_le_bytes(size) - 4).to_le_bytes());
+        assert!(matches!(ObjectDatabase::from_bytes(&corrupt), Err(Error::Io(err)) if err.kind() == std::io::ErrorKind::InvalidData));
+    }
+
+    #[test]
+    fn shipped_object_databases() {
+        for (path, bytes) in crate::samples::files("kidsobjdb") {
+            let database = ObjectDatabase::from_bytes(&bytes).unwrap_or_else(|err| panic!("{}: {}", path.display(), err));
+
+            assert!(!database.objects.is_empty(), "{} has no objects", path.display());
+            assert_eq!(database.to_bytes(), bytes, "{} isn't rebuilt as is", path.display());
+        }
+    }
+
+    #[test]
+    fn unknown_property_type() {
+        let mut bytes = database().to_bytes();
+        // Type of the first property of the first object
+        bytes[OBJDB_HEADER_SIZE as usize + OBJECT_HEADER_SIZE as usize] = 0x20;
+
+        assert!(matches!(ObjectDatabase::from_bytes(&bytes), Err(Error::UnknownPropertyType(0x20))));
+    }
+}
@@ -1,67 +1,185 @@
-use std::{fs::OpenOptions, io::{Seek, SeekFrom, BufReader, BufWriter, Read}, path::{PathBuf, Path}, str::Utf8Error};
+use std::{borrow::Cow, io::{Seek, SeekFrom, BufReader}, path::{PathBuf, Path}, str::Utf8Error};
 
-use binread::{BinRead, NullString, BinResult, BinReaderExt};
+use binread::{BinRead, NullString, BinReaderExt};
 
 use binwrite::BinWrite;
 
 use modular_bitfield::prelude::*;
 
+use crate::compression::Compression;
+use crate::error::{Error, Result};
+use crate::idrk::{Idrk, IDRK_MAGIC, IDRK_VERSION};
+use crate::ktid::KTID;
+use crate::typeinfo::{self, TypeInfo};
+
+pub const RDB_MAGIC: u32 = u32::from_le_bytes(*b"_DRK");
+pub const RDB_VERSION: u32 = 0x30303030;
+
 #[derive(BinRead, BinWrite, Debug)]
 pub struct RdbHeader {
+    #[br(assert(magic == RDB_MAGIC, Error::BadMagic { expected: RDB_MAGIC, found: magic }))]
     pub magic: u32,
+    #[br(assert(version == RDB_VERSION, Error::BadVersion(version)))]
     pub version: u32,
-    #[br(assert(version == 0x30303030))]
     pub header_size: u32,
     pub system_id: u32,
     pub file_count: u32,
-    pub ktid: u32,
+    pub ktid: KTID,
     #[br(map = NullString::into_string)]
+    #[binwrite(cstr)]
     pub path: String,
+    // Whatever is between the path and the entries, kept so writing the RDB gives back the same file
+    #[br(count = (header_size as usize).saturating_sub(RDB_HEADER_SIZE as usize + path.len() + 1))]
+    pub padding: Vec<u8>,
+}
+
+/// Size of the header before the path
+pub const RDB_HEADER_SIZE: u32 = 0x18;
+
+impl RdbHeader {
+    /// Recompute the header size from the path and padding
+    pub fn update_size(&mut self) {
+        self.header_size = RDB_HEADER_SIZE + self.path.len() as u32 + 1 + self.padding.len() as u32;
+    }
 }
 
 #[derive(BinRead, BinWrite, Debug, Clone)]
 pub struct RdbEntry {
+    #[br(assert(magic == IDRK_MAGIC, Error::BadMagic { expected: IDRK_MAGIC, found: magic }))]
     pub magic: u32,
+    #[br(assert(version == IDRK_VERSION, Error::BadVersion(version)))]
     pub version: u32,
-    #[br(assert(version == 0x30303030))]
     pub entry_size: u32,
     pub unk: u32,
     pub string_size: u32,
     pub unk2: u32,
     pub file_size: u64,
     pub entry_type: u32,
-    pub file_ktid: u32,
-    pub type_info_ktid: u32,
+    pub file_ktid: KTID,
+    pub type_info_ktid: KTID,
     pub flags: RdbFlags,
-    #[br(count = (entry_size - string_size) - 0x30)]
+    #[br(count = entry_size.saturating_sub(string_size).saturating_sub(0x30))]
     pub unk_content: Vec<u8>,
     #[br(count = string_size, align_after = 4)]
     #[binwrite(align_after(4))]
     pub name: Vec<u8>,
 }
 
-#[derive(BinRead, BinWrite, Debug, Clone)]
-pub struct IdrkEntry {
-    pub magic: u32,
-    pub version: u32,
-    #[br(assert(version == 0x30303030))]
-    pub entry_size: u32,
+/// KTID of a resource referenced by an entry
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RdbDependency {
+    pub file_ktid: KTID,
+    pub type_info_ktid: KTID,
+}
+
+/// Decoded view of the extra info found between the fixed fields and the name (`unk_content`).
+/// It starts with 8 bytes, followed by `entry_type` KTID slots and padding to the next 16 bytes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RdbExtraInfo {
     pub unk: u32,
-    pub string_size: u32,
     pub unk2: u32,
-    pub file_size: u64,
-    pub entry_type: u32,
-    pub file_ktid: u32,
-    pub type_info_ktid: u32,
-    pub flags: RdbFlags,
-    #[br(count = (entry_size - string_size) - 0x30)]
-    pub unk_content: Vec<u8>,
-    #[br(count = string_size, align_after = 4)]
-    #[binwrite(align_after(4))]
-    pub name: Vec<u8>,
+    /// Slots read in pairs of file KTID and TypeInfo KTID
+    pub dependencies: Vec<RdbDependency>,
+    /// Lone slot when the amount of slots is odd (entry type 1)
+    pub extra_ktid: Option<KTID>,
+    /// Bytes following the slots, kept to write them back as is
+    pub padding: Vec<u8>,
+}
+
+impl RdbExtraInfo {
+    /// Returns None if the extra info is too short for the amount of slots of the entry type
+    pub fn from_bytes(bytes: &[u8], entry_type: u32) -> Option<Self> {
+        let read_u32 = |offset: usize| -> Option<u32> {
+            let slice = bytes.get(offset..offset + 4)?;
+            Some(u32::from_le_bytes([slice[0], slice[1], slice[2], slice[3]]))
+        };
+
+        let slot_count = entry_type as usize;
+        let slots = (0..slot_count).map(|slot| read_u32(8 + slot * 4)).collect::<Option<Vec<u32>>>()?;
+
+        let dependencies = slots.chunks_exact(2).map(|pair| RdbDependency { file_ktid: KTID(pair[0]), type_info_ktid: KTID(pair[1]) }).collect();
+        let extra_ktid = if slot_count % 2 == 1 { slots.last().copied().map(KTID) } else { None };
+
+        Some(Self {
+            unk: read_u32(0)?,
+            unk2: read_u32(4)?,
+            dependencies,
+            extra_ktid,
+            padding: bytes[8 + slot_count * 4..].to_vec(),
+        })
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+
+        bytes.extend_from_slice(&self.unk.to_le_bytes());
+        bytes.extend_from_slice(&self.unk2.to_le_bytes());
+
+        for dependency in &self.dependencies {
+            bytes.extend_from_slice(&dependency.file_ktid.as_u32().to_le_bytes());
+            bytes.extend_from_slice(&dependency.type_info_ktid.as_u32().to_le_bytes());
+        }
+
+        if let Some(ktid) = self.extra_ktid {
+            bytes.extend_from_slice(&ktid.as_u32().to_le_bytes());
+        }
+
+        bytes.extend_from_slice(&self.padding);
+        bytes
+    }
+}
+
+/// Directory, relative to the RDB, where external files are stored
+pub const EXTERNAL_DIR: &str = "data";
+
+/// Where the payload of an entry is stored
+#[derive(Debug, Clone, PartialEq)]
+pub enum RdbLocation {
+    /// Loose `0x????????.file` next to the RDB, in the external directory
+    External(PathBuf),
+    /// IDRK blob stored at `offset` in a .fdata container next to the RDB
+    Internal { fdata: PathBuf, offset: u64 },
 }
 
 impl RdbEntry {
+    /// Create an empty uncompressed entry
+    pub fn new(file_ktid: KTID, type_info_ktid: KTID, entry_type: u32) -> Result<Self> {
+        let extra_size = crate::idrk::extra_info_size(entry_type).ok_or(Error::UnknownEntryType(entry_type))?;
+        let unk_content = vec![0; extra_size as usize];
+
+        let mut entry = Self {
+            magic: IDRK_MAGIC,
+            version: IDRK_VERSION,
+            entry_size: crate::idrk::IDRK_HEADER_SIZE + unk_content.len() as u32,
+            unk: 0,
+            string_size: 0,
+            unk2: 0,
+            file_size: 0,
+            entry_type,
+            file_ktid,
+            type_info_ktid,
+            flags: RdbFlags::new(),
+            unk_content,
+            name: vec![],
+        };
+
+        // Placeholder location, the size marker is filled when a file is set
+        let name = format!("{}@0", entry.get_external_path().display());
+        entry.set_name(&name);
+
+        Ok(entry)
+    }
+
+    /// Decode the extra info according to the entry type
+    pub fn extra_info(&self) -> Option<RdbExtraInfo> {
+        RdbExtraInfo::from_bytes(&self.unk_content, self.entry_type)
+    }
+
+    pub fn set_extra_info(&mut self, extra_info: &RdbExtraInfo) {
+        self.unk_content = extra_info.to_bytes();
+        self.update_size();
+    }
+
     pub fn get_external_path(&self) -> PathBuf {
         PathBuf::from(&format!("0x{:08x}.file", self.file_ktid))
     }
@@ -71,114 +189,137 @@ impl RdbEntry {
         self.flags.set_internal(false);
     }
 
-    pub fn make_uncompressed(&mut self) {
-        self.flags.set_zlib_compressed(false);
-        self.flags.set_lz4_compressed(false);
+    pub fn make_internal(&mut self) {
+        self.flags.set_external(false);
+        self.flags.set_internal(true);
     }
 
-    pub fn get_name(&mut self) -> &str {
-        std::str::from_utf8(self.name.as_slice()).unwrap()
+    /// Clear the location bits so the game can't find the file anymore
+    pub fn disable(&mut self) {
+        self.flags.set_external(false);
+        self.flags.set_internal(false);
     }
 
-    pub fn get_name_mut(&mut self) -> Result<&mut str, Utf8Error> {
-        std::str::from_utf8_mut(self.name.as_mut_slice())
+    pub fn is_disabled(&self) -> bool {
+        !self.flags.external() && !self.flags.internal()
     }
 
-    pub fn set_external_file(&mut self, path: &std::path::Path) {
-        let mut name = if let Ok(name) = self.get_name_mut() {
-            name.to_string()
-        } else {
-            String::new()
-        };
+    /// Recompute the sizes of the entry from its extra info and name
+    pub fn update_size(&mut self) {
+        self.string_size = self.name.len() as u32;
+        self.entry_size = crate::idrk::IDRK_HEADER_SIZE + self.unk_content.len() as u32 + self.string_size;
+    }
 
-        self.file_size = path.metadata().unwrap().len();
+    pub fn make_uncompressed(&mut self) {
+        self.flags.set_storage_mode(StorageMode::Uncompressed);
+    }
 
-        if let Some(size_marker) = name.find('@') {
-            name.replace_range(size_marker.., &format!("@{:x}", self.file_size));
+    pub fn storage_mode(&self) -> StorageMode {
+        self.flags.storage_mode()
+    }
+
+    pub fn set_storage_mode(&mut self, mode: StorageMode) {
+        self.flags.set_storage_mode(mode);
+    }
+
+    pub fn get_name(&self) -> Cow<'_, str> {
+        String::from_utf8_lossy(&self.name)
+    }
+
+    /// Resolve where the payload is stored from the flags and the location string (`fdata@offset@size`)
+    pub fn get_location(&self) -> Option<RdbLocation> {
+        if self.flags.external() {
+            return Some(RdbLocation::External(Path::new(EXTERNAL_DIR).join(self.get_external_path())));
         }
 
-        if self.file_size == 0 {
-            println!("Filesize is 0. Are you sure about that?");
+        if !self.flags.internal() {
+            return None;
         }
 
-        // Remove the size of the original string
-        self.entry_size -= self.string_size;
-        // Put the edited name back into the entry
-        self.name = name.as_bytes().to_vec();
-        // Fix the size of the string
-        self.string_size = name.len() as _;
-        // Edit the size of the entry to take the new name into account
-        self.entry_size += self.string_size;
+        let name = std::str::from_utf8(&self.name).ok()?;
+        let mut parts = name.trim_end_matches('\0').split('@');
+
+        let fdata = PathBuf::from(parts.next()?);
+        let offset = u64::from_str_radix(parts.next()?.trim_start_matches("0x"), 16).ok()?;
 
-        let mut ext_entry = self.clone();
-        ext_entry.patch_external_file(path);
+        Some(RdbLocation::Internal { fdata, offset })
     }
 
-    pub fn patch_external_file(&mut self, path: &std::path::Path) {
-        self.name = vec![];
-        //self.write(&mut bytes).unwrap();
+    /// Size marker at the end of the name (`@size`), in hexadecimal
+    pub fn get_size_marker(&self) -> Option<&str> {
+        let name = std::str::from_utf8(&self.name).ok()?;
+        name.trim_end_matches('\0').rsplit_once('@').map(|(_, marker)| marker)
+    }
 
-        //let cursor : &mut std::io::Cursor<Vec<u8>> = &mut std::io::Cursor::new(Vec::new());
+    pub fn get_name_mut(&mut self) -> std::result::Result<&mut str, Utf8Error> {
+        std::str::from_utf8_mut(self.name.as_mut_slice())
+    }
 
-        let mut test = OpenOptions::new().read(true).write(true).create(true).open(path).unwrap();
-        
-        //let file = std::fs::read(path).unwrap();
+    /// IDRK blob storing `data` for the entry. Raw files are wrapped according to the entry, IDRK blobs are kept as is once their KTID is checked,
+    /// the entry taking their storage mode. The file size of the entry is updated either way.
+    pub fn idrk_for(&mut self, data: &[u8]) -> Result<Idrk> {
+        if !data.starts_with(b"IDRK") {
+            self.file_size = data.len() as u64;
+            return Idrk::from_entry(self, data);
+        }
 
-        let mut buffer = Vec::new();        
-        
-        let mut out_sig = [0;4];
-        test.read(&mut out_sig).unwrap();
+        let idrk = Idrk::from_bytes(data)?;
 
-        if &out_sig == b"IDRK" {
-            println!("Already patched");
-            return;
+        if idrk.header.file_ktid != self.file_ktid {
+            return Err(Error::KtidMismatch { expected: self.file_ktid, found: idrk.header.file_ktid });
         }
 
-        test.seek(SeekFrom::Start(0)).unwrap();
+        self.set_storage_mode(idrk.header.flags.storage_mode());
+        self.file_size = idrk.header.file_size;
+
+        Ok(idrk)
+    }
+
+    /// Store `path` as the external file of the entry, in the `data` directory next to the RDB. `rdb_dir` is the directory of the RDB being written.
+    /// Raw files are wrapped in an IDRK blob, IDRK blobs are copied as is, see [`RdbEntry::idrk_for`].
+    pub fn set_external_file(&mut self, path: &Path, rdb_dir: &Path) -> Result<()> {
+        let data = std::fs::read(path)?;
 
-        let header_size = match self.entry_type {
-            0 => 0x38,
-            // 1 is KidsSingletonDb? 4 is G1E
-            1 | 4 => 0x48,
-            // G1A, G1T
-            8 => 0x58,
-            // G1M, most likely other model related formats
-            12 => 0x68,
-            _ => panic!("Unknown entry type found: {}", self.entry_type)
+        let idrk = self.idrk_for(&data)?;
+        let idrk = match data.starts_with(b"IDRK") {
+            true => data,
+            false => idrk.to_bytes()?,
         };
-        self.entry_size = header_size + test.metadata().unwrap().len() as u32;
-        self.file_size = test.metadata().unwrap().len() as _;
-        self.string_size = self.file_size as _;
-        self.flags = RdbFlags::new();
-
-        //test.seek(SeekFrom::Start(0)).unwrap();
-        self.write(&mut buffer).unwrap();
-        test.read_to_end(&mut buffer).unwrap();
-        
-        // let mut writer = BufWriter::new(test);
-        // writer.seek(SeekFrom::Start(0)).unwrap();
-
-        // self.write(&mut writer).unwrap();
-        //writer.write_all(&mut reader.buffer());
-        // Check if we're dealing with a KTID or an actual filename
-        let filename = if path.file_name().unwrap().to_str().unwrap().starts_with("0x") {
-            // Strip the extension (Cethleann keeps the extension even if the hash is missing)
-            path.file_stem().unwrap().to_str().unwrap()
+
+        let mut name = if let Ok(name) = self.get_name_mut() {
+            name.to_string()
         } else {
-            // Get the full filename with extension
-            path.file_name().unwrap().to_str().unwrap()
+            String::new()
         };
 
-        let out_path = PathBuf::from(format!("./data/0x{}.file", crate::ktid(filename)));
-
-        if !out_path.exists() {
-            std::fs::create_dir_all("./data/").unwrap();
+        if let Some(size_marker) = name.find('@') {
+            name.replace_range(size_marker.., &format!("@{:x}", self.file_size));
         }
 
-        match std::fs::write(out_path, &buffer) {
-            Ok(_) => {},
-            Err(err) => panic!("{}", err),
-        };
+        self.set_name(&name);
+
+        let out_dir = rdb_dir.join(EXTERNAL_DIR);
+        std::fs::create_dir_all(&out_dir)?;
+        std::fs::write(out_dir.join(self.get_external_path()), idrk)?;
+
+        Ok(())
+    }
+
+    /// Point the entry to an IDRK blob stored at `offset` in a .fdata container
+    pub fn set_internal_file(&mut self, fdata: &str, offset: u64, file_size: u64) {
+        self.file_size = file_size;
+        self.set_name(&format!("{}@{:x}@{:x}", fdata, offset, file_size));
+    }
+
+    fn set_name(&mut self, name: &str) {
+        // Remove the size of the original string
+        self.entry_size -= self.string_size;
+        // Put the edited name back into the entry
+        self.name = name.as_bytes().to_vec();
+        // Fix the size of the string
+        self.string_size = name.len() as _;
+        // Edit the size of the entry to take the new name into account
+        self.entry_size += self.string_size;
     }
 }
 
@@ -188,27 +329,122 @@ impl RdbEntry {
 pub struct Rdb {
     pub header: RdbHeader,
     #[br(seek_before = SeekFrom::Start(header.header_size as _), count = header.file_count)]
-    #[binwrite(align(4))]
     pub entries: Vec<RdbEntry>,
 }
 
 impl Rdb {
-    pub fn open<P: AsRef<Path>>(path: P) -> BinResult<Self> {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
         Self::from_reader(std::io::BufReader::new(std::fs::File::open(path)?))
     }
 
-    pub fn from_reader<R: std::io::Read + std::io::Seek + Send + 'static>(mut reader: R) -> BinResult<Self> {
+    pub fn from_reader<R: std::io::Read + std::io::Seek>(mut reader: R) -> Result<Self> {
         let rdb: Self = reader.read_le()?;
 
         Ok(rdb)
     }
 
-    pub fn get_entry_by_ktid(&self, ktid: crate::ktid::KTID) -> Option<&RdbEntry> {
-        self.entries.iter().find(|x| x.file_ktid == ktid.as_u32())
+    pub fn get_entry_by_ktid(&self, ktid: KTID) -> Option<&RdbEntry> {
+        self.entries.iter().find(|x| x.file_ktid == ktid)
+    }
+
+    pub fn get_entry_by_ktid_mut(&mut self, ktid: KTID) -> Option<&mut RdbEntry> {
+        self.entries.iter_mut().find(|x| x.file_ktid == ktid)
+    }
+
+    /// Entries whose TypeInfo is exactly `T`
+    pub fn entries_of<T: TypeInfo>(&self) -> impl Iterator<Item = &RdbEntry> {
+        self.entries.iter().filter(|entry| entry.type_info_ktid == T::ID)
+    }
+
+    /// Entries whose TypeInfo is `T` or any type below it, such as every light for `typeinfo::object::kt3d::placeable::light::Type`
+    pub fn entries_under<T: TypeInfo>(&self) -> impl Iterator<Item = &RdbEntry> {
+        self.entries.iter().filter(|entry| entry.type_info_ktid == T::ID || typeinfo::is_a(entry.type_info_ktid, T::NAME))
+    }
+
+    /// Remove an entry and update the file count
+    pub fn remove_entry(&mut self, ktid: KTID) -> Option<RdbEntry> {
+        let index = self.entries.iter().position(|x| x.file_ktid == ktid)?;
+        let entry = self.entries.remove(index);
+
+        self.header.file_count = self.entries.len() as u32;
+
+        Some(entry)
     }
 
-    pub fn get_entry_by_ktid_mut(&mut self, ktid: crate::ktid::KTID) -> Option<&mut RdbEntry> {
-        self.entries.iter_mut().find(|x| x.file_ktid == ktid.as_u32())
+    /// Append a new entry and update the file count. Returns None if an entry with the same KTID already exists.
+    pub fn add_entry(&mut self, entry: RdbEntry) -> Option<&mut RdbEntry> {
+        if self.entries.iter().any(|x| x.file_ktid == entry.file_ktid) {
+            return None;
+        }
+
+        self.entries.push(entry);
+        self.header.file_count = self.entries.len() as u32;
+
+        self.entries.last_mut()
+    }
+
+    /// Recompute the file count and the size of every entry
+    pub fn update_sizes(&mut self) {
+        self.header.update_size();
+        self.header.file_count = self.entries.len() as u32;
+        self.entries.iter_mut().for_each(RdbEntry::update_size);
+    }
+
+    /// Fix the sizes and write the RDB to a file
+    pub fn save<P: AsRef<Path>>(&mut self, path: P) -> Result<()> {
+        self.update_sizes();
+        std::fs::write(path, self.to_bytes()?)?;
+
+        Ok(())
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        Self::from_reader(binread::io::Cursor::new(bytes))
+    }
+
+    /// Serialize the RDB as is, without fixing the sizes
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let mut bytes = vec![];
+        self.write(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Read the payload of an entry as it is stored on disk. `rdb_dir` is the directory containing the RDB.
+    pub fn read_entry_data<P: AsRef<Path>>(&self, entry: &RdbEntry, rdb_dir: P) -> Result<Vec<u8>> {
+        let (path, offset) = match entry.get_location() {
+            Some(RdbLocation::External(path)) => (rdb_dir.as_ref().join(path), 0),
+            Some(RdbLocation::Internal { fdata, offset }) => (rdb_dir.as_ref().join(fdata), offset),
+            None => return Err(Error::NoLocation(entry.file_ktid)),
+        };
+
+        if entry.flags.external() && !path.exists() {
+            return Err(Error::MissingExternalFile(path));
+        }
+
+        let mut reader = BufReader::new(std::fs::File::open(path)?);
+        reader.seek(SeekFrom::Start(offset))?;
+
+        let idrk: Idrk = reader.read_le()?;
+
+        Ok(idrk.data)
+    }
+
+    /// Read the payload of an entry and decompress it if needed. The result is checked against `file_size`.
+    pub fn read_entry<P: AsRef<Path>>(&self, entry: &RdbEntry, rdb_dir: P) -> Result<Vec<u8>> {
+        if entry.storage_mode() == StorageMode::Encrypted {
+            return Err(Error::Encrypted(entry.file_ktid));
+        }
+
+        let data = self.read_entry_data(entry, rdb_dir)?;
+        let size = entry.file_size as usize;
+
+        let data = entry.storage_mode().decode(&data, size)?;
+
+        if data.len() != size {
+            return Err(Error::SizeMismatch { ktid: entry.file_ktid, expected: entry.file_size, found: data.len() as u64 });
+        }
+
+        Ok(data)
     }
 }
 
@@ -220,8 +456,365 @@ pub struct RdbFlags {
     pub external: bool,
     pub internal: bool,
     pub unk2: B2,
-    // If both are set, file is encrypted
+    // If both are set, file is encrypted. Use storage_mode() instead of checking them by hand
     pub zlib_compressed: bool,
     pub lz4_compressed: bool,
     pub unk3: B10,
 }
+
+impl Default for RdbFlags {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RdbFlags {
+    pub fn storage_mode(&self) -> StorageMode {
+        match (self.zlib_compressed(), self.lz4_compressed()) {
+            (false, false) => StorageMode::Uncompressed,
+            (true, false) => StorageMode::Zlib,
+            (false, true) => StorageMode::Lz4,
+            (true, true) => StorageMode::Encrypted,
+        }
+    }
+
+    pub fn set_storage_mode(&mut self, mode: StorageMode) {
+        let (zlib, lz4) = match mode {
+            StorageMode::Uncompressed => (false, false),
+            StorageMode::Zlib => (true, false),
+            StorageMode::Lz4 => (false, true),
+            StorageMode::Encrypted => (true, true),
+        };
+
+        self.set_zlib_compressed(zlib);
+        self.set_lz4_compressed(lz4);
+    }
+}
+
+/// How the payload of an entry is stored, as described by the compression bits of the flags
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StorageMode {
+    Uncompressed,
+    Zlib,
+    Lz4,
+    /// Both compression bits are set
+    Encrypted,
+}
+
+impl StorageMode {
+    pub fn compression(self) -> Option<Compression> {
+        match self {
+            StorageMode::Zlib => Some(Compression::Zlib),
+            StorageMode::Lz4 => Some(Compression::Lz4),
+            _ => None,
+        }
+    }
+
+    /// Compress raw data for this storage mode. Encryption isn't supported.
+    pub fn encode(self, data: &[u8]) -> std::io::Result<Vec<u8>> {
+        match self {
+            StorageMode::Uncompressed => Ok(data.to_vec()),
+            StorageMode::Zlib => crate::compression::compress(Compression::Zlib, data),
+            StorageMode::Lz4 => crate::compression::compress(Compression::Lz4, data),
+            StorageMode::Encrypted => Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, "Encrypting files isn't supported")),
+        }
+    }
+
+    /// Decompress stored data for this storage mode. `size` is the expected size of the decompressed data.
+    pub fn decode(self, data: &[u8], size: usize) -> std::io::Result<Vec<u8>> {
+        match self {
+            StorageMode::Uncompressed => Ok(data.to_vec()),
+            StorageMode::Zlib => crate::compression::decompress_zlib(data, size),
+            StorageMode::Lz4 => crate::compression::decompress_lz4(data, size),
+            StorageMode::Encrypted => Err(std::io::Error::new(std::io::ErrorKind::InvalidData, "Decrypting files isn't supported")),
+        }
+    }
+}
+
+impl From<Compression> for StorageMode {
+    fn from(compression: Compression) -> Self {
+        match compression {
+            Compression::Zlib => StorageMode::Zlib,
+            Compression::Lz4 => StorageMode::Lz4,
+        }
+    }
+}
+
+impl std::fmt::Display for StorageMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            StorageMode::Uncompressed => "uncompressed",
+            StorageMode::Zlib => "zlib",
+            StorageMode::Lz4 => "lz4",
+            StorageMode::Encrypted => "encrypted",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+/// Builders for synthetic RDBs, written by hand rather than through BinWrite so they can be used to check it
+#[cfg(test)]
+pub(crate) mod testing {
+    pub fn synthetic_entry(file_ktid: u32, entry_type: u32, name: &str, flags: u32) -> Vec<u8> {
+        let extra_size = crate::idrk::extra_info_size(entry_type).unwrap_or(8) as usize;
+
+        let mut bytes = vec![];
+        bytes.extend_from_slice(b"IDRK0000");
+        bytes.extend_from_slice(&((0x30 + extra_size + name.len()) as u32).to_le_bytes());
+        bytes.extend_from_slice(&0u32.to_le_bytes());
+        bytes.extend_from_slice(&(name.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&0u32.to_le_bytes());
+        bytes.extend_from_slice(&0x1234u64.to_le_bytes());
+        bytes.extend_from_slice(&entry_type.to_le_bytes());
+        bytes.extend_from_slice(&file_ktid.to_le_bytes());
+        bytes.extend_from_slice(&0xabcdef01u32.to_le_bytes());
+        bytes.extend_from_slice(&flags.to_le_bytes());
+        bytes.extend((0..extra_size).map(|i| i as u8));
+        bytes.extend_from_slice(name.as_bytes());
+
+        let padding = (4 - bytes.len() % 4) % 4;
+        bytes.resize(bytes.len() + padding, 0);
+
+        bytes
+    }
+
+    pub fn synthetic_rdb(path: &str, entries: &[Vec<u8>]) -> Vec<u8> {
+        let header_size = (0x18 + path.len() + 1).div_ceil(4) * 4;
+
+        let mut bytes = vec![];
+        bytes.extend_from_slice(b"_DRK0000");
+        bytes.extend_from_slice(&(header_size as u32).to_le_bytes());
+        bytes.extend_from_slice(&0u32.to_le_bytes());
+        bytes.extend_from_slice(&(entries.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&0x1234u32.to_le_bytes());
+        bytes.extend_from_slice(path.as_bytes());
+        bytes.resize(header_size, 0);
+
+        entries.iter().for_each(|entry| bytes.extend_from_slice(entry));
+
+        bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use testing::*;
+
+    fn empty_rdb() -> Rdb {
+        Rdb::from_bytes(&synthetic_rdb("data", &[])).unwrap()
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut entries = vec![];
+
+        // Every entry type, with names covering every alignment
+        for (i, entry_type) in [0, 1, 4, 8, 12].iter().enumerate() {
+            for name_len in 0..8 {
+                let name = "abcdefgh@1"[..name_len].to_string();
+                let flags = if i % 2 == 0 { 1 << 16 } else { (1 << 17) | (1 << 20) };
+                entries.push(synthetic_entry((i * 8 + name_len) as u32, *entry_type, &name, flags));
+            }
+        }
+
+        for path in ["", "a", "data", "data/", "system/data"] {
+            let bytes = synthetic_rdb(path, &entries);
+            let mut rdb = Rdb::from_bytes(&bytes).unwrap();
+
+            assert_eq!(rdb.entries.len(), entries.len());
+            assert_eq!(rdb.to_bytes().unwrap(), bytes, "path '{}'", path);
+
+            // Fixing the sizes of a valid RDB doesn't change anything
+            rdb.update_sizes();
+            assert_eq!(rdb.to_bytes().unwrap(), bytes, "path '{}' after update_sizes", path);
+        }
+    }
+
+    #[test]
+    fn round_trip_keeps_header_padding() {
+        let mut bytes = synthetic_rdb("data", &[synthetic_entry(1, 0, "x@3", 1 << 16)]);
+        // Garbage between the path and the first entry
+        bytes[0x1e] = 0xCC;
+
+        let rdb = Rdb::from_bytes(&bytes).unwrap();
+        assert_eq!(rdb.header.path, "data");
+        assert_eq!(rdb.to_bytes().unwrap(), bytes);
+    }
+
+    #[test]
+    fn bad_headers() {
+        let mut bytes = synthetic_rdb("data", &[synthetic_entry(1, 0, "x@3", 1 << 16)]);
+
+        bytes[4] = b'1';
+        assert!(matches!(Rdb::from_bytes(&bytes), Err(Error::BadVersion(0x30303031))));
+
+        bytes[0] = b'X';
+        assert!(matches!(Rdb::from_bytes(&bytes), Err(Error::BadMagic { expected: RDB_MAGIC, .. })));
+
+        let mut bytes = synthetic_rdb("data", &[synthetic_entry(1, 0, "x@3", 1 << 16)]);
+        bytes[0x20] = b'X';
+        assert!(matches!(Rdb::from_bytes(&bytes), Err(Error::BadMagic { expected: IDRK_MAGIC, .. })));
+
+        assert!(matches!(Rdb::from_bytes(&bytes[..0x10]), Err(Error::Io(_))));
+    }
+
+    #[test]
+    fn read_missing_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut rdb = Rdb::from_bytes(&synthetic_rdb("data", &[synthetic_entry(1, 0, "x@3", 1 << 16)])).unwrap();
+
+        assert!(matches!(rdb.read_entry(&rdb.entries[0], dir.path()), Err(Error::MissingExternalFile(_))));
+
+        rdb.entries[0].disable();
+        assert!(matches!(rdb.read_entry(&rdb.entries[0], dir.path()), Err(Error::NoLocation(KTID(1)))));
+
+        rdb.entries[0].set_storage_mode(StorageMode::Encrypted);
+        assert!(matches!(rdb.read_entry(&rdb.entries[0], dir.path()), Err(Error::Encrypted(KTID(1)))));
+    }
+
+    #[test]
+    fn external_files_go_next_to_the_rdb() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("0x00000001.g1t");
+        std::fs::write(&source, b"GT1G external").unwrap();
+
+        let mut rdb = Rdb::from_bytes(&synthetic_rdb("data", &[synthetic_entry(1, 8, "0x00000001.file@3", 1 << 16)])).unwrap();
+        rdb.entries[0].set_storage_mode(StorageMode::Lz4);
+        rdb.entries[0].set_external_file(&source, &dir.path().join("out")).unwrap();
+
+        let entry = &rdb.entries[0];
+        assert!(dir.path().join("out").join(EXTERNAL_DIR).join("0x00000001.file").exists());
+        assert_eq!((entry.get_name().as_ref(), entry.file_size), ("0x00000001.file@d", 13));
+        assert_eq!(rdb.read_entry(entry, dir.path().join("out")).unwrap(), b"GT1G external");
+
+        // IDRK blobs are copied as is, keeping their storage mode
+        let idrk = dir.path().join("out").join(EXTERNAL_DIR).join("0x00000001.file");
+        let mut copy = Rdb::from_bytes(&synthetic_rdb("data", &[synthetic_entry(1, 8, "0x00000001.file@3", 1 << 16)])).unwrap();
+        copy.entries[0].set_external_file(&idrk, &dir.path().join("copy")).unwrap();
+        assert_eq!(copy.entries[0].storage_mode(), StorageMode::Lz4);
+        assert_eq!(std::fs::read(dir.path().join("copy").join(EXTERNAL_DIR).join("0x00000001.file")).unwrap(), std::fs::read(&idrk).unwrap());
+        assert_eq!(copy.read_entry(&copy.entries[0], dir.path().join("copy")).unwrap(), b"GT1G external");
+
+        let mut other = Rdb::from_bytes(&synthetic_rdb("data", &[synthetic_entry(2, 8, "0x00000002.file@3", 1 << 16)])).unwrap();
+        assert!(matches!(other.entries[0].set_external_file(&idrk, dir.path()), Err(Error::KtidMismatch { expected: KTID(2), found: KTID(1) })));
+    }
+
+    #[test]
+    fn round_trip_through_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let bytes = synthetic_rdb("data", &[synthetic_entry(1, 8, "system.fdata@10@5", 1 << 17)]);
+
+        let mut rdb = Rdb::from_bytes(&bytes).unwrap();
+        rdb.save(dir.path().join("out.rdb")).unwrap();
+
+        assert_eq!(std::fs::read(dir.path().join("out.rdb")).unwrap(), bytes);
+        assert_eq!(Rdb::open(dir.path().join("out.rdb")).unwrap().entries[0].file_ktid, KTID(1));
+    }
+
+    #[test]
+    fn add_entry() {
+        let mut rdb = empty_rdb();
+
+        let entry = RdbEntry::new(KTID(0x1234), KTID(0x5678), 8).unwrap();
+        assert_eq!(entry.entry_size, 0x58 + entry.string_size);
+        assert_eq!(entry.get_name(), "0x00001234.file@0");
+
+        assert!(rdb.add_entry(entry.clone()).is_some());
+        assert!(rdb.add_entry(entry).is_none());
+        assert_eq!(rdb.header.file_count, 1);
+
+        assert!(RdbEntry::new(KTID(0), KTID(0), 3).is_err());
+    }
+
+    #[test]
+    fn extra_info_round_trip() {
+        for entry_type in [0, 1, 4, 8, 12] {
+            let bytes: Vec<u8> = (0..crate::idrk::extra_info_size(entry_type).unwrap() as u8).collect();
+
+            let extra_info = RdbExtraInfo::from_bytes(&bytes, entry_type).unwrap();
+            assert_eq!(extra_info.dependencies.len(), entry_type as usize / 2);
+            assert_eq!(extra_info.extra_ktid.is_some(), entry_type == 1);
+            assert_eq!(extra_info.to_bytes(), bytes);
+        }
+
+        let extra_info = RdbExtraInfo::from_bytes(&(0..0x28).collect::<Vec<u8>>(), 8).unwrap();
+        assert_eq!(extra_info.dependencies[0], RdbDependency { file_ktid: KTID(0x0b0a0908), type_info_ktid: KTID(0x0f0e0d0c) });
+        assert!(extra_info.padding.is_empty());
+
+        assert!(RdbExtraInfo::from_bytes(&[0; 8], 4).is_none());
+    }
+
+    #[test]
+    fn set_extra_info() {
+        let mut entry = RdbEntry::new(KTID(1), KTID(0), 4).unwrap();
+        let mut extra_info = entry.extra_info().unwrap();
+
+        extra_info.dependencies[1].file_ktid = KTID(0xdeadbeef);
+        entry.set_extra_info(&extra_info);
+
+        assert_eq!(entry.extra_info().unwrap().dependencies[1].file_ktid, KTID(0xdeadbeef));
+        assert_eq!(entry.unk_content.len(), 0x18);
+    }
+
+    #[test]
+    fn remove_and_disable_entries() {
+        let mut rdb = empty_rdb();
+
+        for ktid in 1..=3 {
+            let mut entry = RdbEntry::new(KTID(ktid), KTID(0), 0).unwrap();
+            entry.make_external();
+            rdb.add_entry(entry);
+        }
+
+        assert_eq!(rdb.remove_entry(KTID(2)).unwrap().file_ktid, KTID(2));
+        assert!(rdb.remove_entry(KTID(2)).is_none());
+        assert_eq!(rdb.header.file_count, 2);
+
+        let entry = rdb.get_entry_by_ktid_mut(KTID(3)).unwrap();
+        entry.disable();
+        assert!(entry.is_disabled());
+        assert_eq!(entry.get_location(), None);
+
+        // Sizes are fixed before writing even if they were edited by hand
+        rdb.entries[0].name = b"renamed@0".to_vec();
+        rdb.header.file_count = 10;
+        rdb.update_sizes();
+        assert_eq!(rdb.header.file_count, 2);
+        assert_eq!(rdb.entries[0].entry_size, 0x38 + 9);
+    }
+
+    #[test]
+    fn typed_entries() {
+        use crate::typeinfo::object::kt3d::placeable::light;
+
+        let mut rdb = empty_rdb();
+        rdb.add_entry(RdbEntry::new(KTID(1), light::point::ID, 0).unwrap());
+        rdb.add_entry(RdbEntry::new(KTID(2), light::spot::ID, 0).unwrap());
+        rdb.add_entry(RdbEntry::new(KTID(3), typeinfo::object::sound::bank::ID, 0).unwrap());
+
+        let ktids = |entries: Vec<&RdbEntry>| entries.iter().map(|entry| entry.file_ktid.as_u32()).collect::<Vec<u32>>();
+
+        assert_eq!(ktids(rdb.entries_of::<light::point::Type>().collect()), [1]);
+        assert_eq!(ktids(rdb.entries_of::<light::Type>().collect()), Vec::<u32>::new());
+        assert_eq!(ktids(rdb.entries_under::<light::Type>().collect()), [1, 2]);
+        assert_eq!(ktids(rdb.entries_under::<typeinfo::object::sound::bank::Type>().collect()), [3]);
+    }
+
+    #[test]
+    fn storage_mode_flags() {
+        let mut flags = RdbFlags::new();
+        assert_eq!(flags.storage_mode(), StorageMode::Uncompressed);
+
+        flags.set_zlib_compressed(true);
+        flags.set_lz4_compressed(true);
+        assert_eq!(flags.storage_mode(), StorageMode::Encrypted);
+
+        flags.set_storage_mode(StorageMode::Lz4);
+        assert!(!flags.zlib_compressed());
+        assert_eq!(flags.storage_mode(), StorageMode::Lz4);
+        assert_eq!(flags.storage_mode().compression(), Some(Compression::Lz4));
+    }
+}
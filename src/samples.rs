@@ -0,0 +1,22 @@
+//! Files shipped with the games, to check the parsers against real data.
+
+/// Files with the given extension in the directory named by `RDB_TOOL_SAMPLES`.
+/// Empty when the variable isn't set, as those files can't be redistributed with the tool.
+pub(crate) fn files(extension: &str) -> Vec<(std::path::PathBuf, Vec<u8>)> {
+    let dir = match std::env::var_os("RDB_TOOL_SAMPLES") {
+        Some(dir) => std::path::PathBuf::from(dir),
+        None => return vec![],
+    };
+
+    let mut paths: Vec<_> = std::fs::read_dir(&dir)
+        .unwrap_or_else(|err| panic!("Couldn't list the samples in '{}': {}", dir.display(), err))
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|found| found.eq_ignore_ascii_case(extension)))
+        .collect();
+    paths.sort();
+
+    paths.into_iter().map(|path| {
+        let bytes = std::fs::read(&path).unwrap();
+        (path, bytes)
+    }).collect()
+}
@@ -0,0 +1,185 @@
+use crate::ktid::{KtidResolver, KTID};
+
+use super::registry::TYPEINFOS;
+
+/// Implemented by the `Type` of every typeinfo module, to refer to TypeInfos in a type-safe way
+pub trait TypeInfo {
+    const ID: KTID;
+    /// Full type path, such as `TypeInfo::Object::Render::Texture::Static`
+    const NAME: &'static str;
+
+    /// Last segment of the type path
+    fn display_name() -> &'static str {
+        Self::NAME.rsplit("::").next().unwrap_or(Self::NAME)
+    }
+
+    /// Extension the files of this type are usually given, see [`extension`]
+    fn extension() -> &'static str {
+        extension(Self::NAME)
+    }
+}
+
+/// Formats with a known extension, the first type path a TypeInfo is under wins
+const EXTENSIONS: &[(&str, &str)] = &[
+    ("TypeInfo::Object::Render::Texture::Static", "g1t"),
+    ("TypeInfo::Object::Render::Texture::StaticForSLE", "g1t"),
+    ("TypeInfo::Object::Animation::Data::Camera::G1A", "g1a"),
+    ("TypeInfo::Object::Animation::Data::Model::G1A", "g1a"),
+    ("TypeInfo::Object::3D::Displayset::Model", "g1m"),
+    ("TypeInfo::Object::3D::Displayset::LODModel", "g1m"),
+    ("TypeInfo::Resource::System::NameDatabaseFile", "ndb"),
+    ("TypeInfo::Resource::System::ObjectDatabaseFile", "kidsobjdb"),
+];
+
+/// Extension the files of a TypeInfo are usually given, `file` if it isn't known
+pub fn extension(path: &str) -> &'static str {
+    EXTENSIONS.iter().find(|(ancestor, _)| is_under(path, ancestor)).map_or("file", |(_, extension)| extension)
+}
+
+/// Full type path of a TypeInfo, such as `TypeInfo::Object::Render::Texture::Static`
+pub fn name(ktid: KTID) -> Option<&'static str> {
+    TYPEINFOS.binary_search_by_key(&ktid.as_u32(), |(id, _, _)| *id).ok().map(|index| TYPEINFOS[index].2)
+}
+
+/// Resolves the KTIDs of the known TypeInfos to their full type path, like [`name`]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Registry;
+
+impl KtidResolver for Registry {
+    fn resolve(&self, ktid: KTID) -> Option<&str> {
+        name(ktid)
+    }
+}
+
+/// Kind of the entry in typeinfos.csv, `TypeInfo` for most of them
+pub fn kind(ktid: KTID) -> Option<&'static str> {
+    TYPEINFOS.binary_search_by_key(&ktid.as_u32(), |(id, _, _)| *id).ok().map(|index| TYPEINFOS[index].1)
+}
+
+/// KTID of a known TypeInfo. The path is case insensitive and the `TypeInfo::` prefix is optional.
+pub fn ktid(name: &str) -> Option<KTID> {
+    let name = name.trim();
+
+    TYPEINFOS.iter()
+        .find(|(_, _, path)| path.eq_ignore_ascii_case(name) || path.strip_prefix("TypeInfo::").is_some_and(|path| path.eq_ignore_ascii_case(name)))
+        .map(|(id, _, _)| KTID(*id))
+}
+
+/// Every known TypeInfo as (KTID, full type path), sorted by KTID
+pub fn all() -> impl Iterator<Item = (KTID, &'static str)> {
+    TYPEINFOS.iter().map(|(id, _, path)| (KTID(*id), *path))
+}
+
+/// Like [`name`], but also finds the groups that only exist as a parent of other types, such as `TypeInfo::Object::3D::Placeable::Light`
+pub fn path(ktid: KTID) -> Option<&'static str> {
+    name(ktid).or_else(|| {
+        TYPEINFOS.iter().find_map(|(_, _, path)| {
+            path.match_indices("::").map(|(index, _)| &path[..index]).find(|parent| crate::ktid::ktid_hash(parent, 31) == ktid.as_u32())
+        })
+    })
+}
+
+/// Path one level up the hierarchy, `TypeInfo::Object::3D::Placeable::Light` for `TypeInfo::Object::3D::Placeable::Light::Point`
+pub fn parent(path: &str) -> Option<&str> {
+    path.rsplit_once("::").map(|(parent, _)| parent)
+}
+
+/// Whether `path` is `ancestor` or somewhere below it. Both are case insensitive and the `TypeInfo::` prefix is optional.
+fn is_under(path: &str, ancestor: &str) -> bool {
+    let path = strip_root(path);
+    let ancestor = strip_root(ancestor.trim());
+
+    if ancestor.is_empty() {
+        return true;
+    }
+
+    path.len() >= ancestor.len()
+        && path.as_bytes()[..ancestor.len()].eq_ignore_ascii_case(ancestor.as_bytes())
+        && (path.len() == ancestor.len() || path[ancestor.len()..].starts_with("::"))
+}
+
+fn strip_root(path: &str) -> &str {
+    match path.get(.."TypeInfo".len()) {
+        Some(root) if root.eq_ignore_ascii_case("TypeInfo") => path["TypeInfo".len()..].trim_start_matches("::"),
+        _ => path,
+    }
+}
+
+/// Every known TypeInfo below `path`, excluding `path` itself
+pub fn descendants(path: &str) -> impl Iterator<Item = (KTID, &'static str)> + '_ {
+    all().filter(move |(_, descendant)| is_under(descendant, path) && !is_under(path, descendant))
+}
+
+/// Whether the TypeInfo is `ancestor` or one of its descendants, such as any light for `TypeInfo::Object::3D::Placeable::Light`
+pub fn is_a(ktid: KTID, ancestor: &str) -> bool {
+    name(ktid).is_some_and(|path| is_under(path, ancestor))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::typeinfo::object;
+
+    #[test]
+    fn registry() {
+        assert!(TYPEINFOS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+
+        for (id, path) in all() {
+            assert_eq!(crate::ktid::ktid(path).unwrap(), id, "{}", path);
+        }
+
+        assert_eq!(name(object::render::texture::r#static::ID), Some("TypeInfo::Object::Render::Texture::Static"));
+        assert_eq!(name(object::kt3d::placeable::light::point::ID), Some("TypeInfo::Object::3D::Placeable::Light::Point"));
+        assert_eq!(name(KTID(0)), None);
+
+        assert_eq!(ktid("TypeInfo::Object::Sound::Bank"), Some(object::sound::bank::ID));
+        assert_eq!(ktid("object::sound::bank"), Some(object::sound::bank::ID));
+        assert_eq!(ktid("Object::Sound"), None);
+
+        assert_eq!(Registry.resolve(object::sound::bank::ID), Some("TypeInfo::Object::Sound::Bank"));
+        assert_eq!(Registry.resolve(KTID(0)), None);
+    }
+
+    #[test]
+    fn type_info_trait() {
+        use object::render::texture::r#static;
+
+        assert_eq!(r#static::Type::ID, r#static::ID);
+        assert_eq!(r#static::Type::NAME, "TypeInfo::Object::Render::Texture::Static");
+        assert_eq!(r#static::Type::display_name(), "Static");
+        assert_eq!(r#static::Type::extension(), "g1t");
+        assert_eq!(object::sound::bank::Type::extension(), "file");
+
+        // Parents that aren't in typeinfos.csv still hash to their path
+        assert_eq!(object::kt3d::placeable::light::Type::ID, crate::ktid::ktid("TypeInfo::Object::3D::Placeable::Light").unwrap());
+        assert_eq!(object::kt3d::placeable::light::Type::display_name(), "Light");
+    }
+
+    #[test]
+    fn hierarchy() {
+        let point = object::kt3d::placeable::light::point::ID;
+
+        assert_eq!(parent("TypeInfo::Object::3D::Placeable::Light::Point"), Some("TypeInfo::Object::3D::Placeable::Light"));
+        assert_eq!(parent("TypeInfo"), None);
+
+        assert!(is_a(point, "TypeInfo::Object::3D::Placeable::Light"));
+        assert!(is_a(point, "object::3d::placeable"));
+        assert!(is_a(point, "TypeInfo::Object::3D::Placeable::Light::Point"));
+        assert!(is_a(point, "TypeInfo"));
+        assert!(!is_a(point, "TypeInfo::Object::3D::Placeable::Light::Spot"));
+        assert!(!is_a(point, "TypeInfo::Object::3D::Place"));
+        assert!(!is_a(KTID(0), "TypeInfo"));
+
+        let lights: Vec<&str> = descendants("TypeInfo::Object::3D::Placeable::Light").map(|(_, path)| path).collect();
+        assert_eq!(lights.len(), 5);
+        assert!(lights.contains(&"TypeInfo::Object::3D::Placeable::Light::Point"));
+
+        // Box is both a type and the parent of the sound boxes
+        let boxes = descendants("TypeInfo::Object::3D::Placeable::Collision::Box").count();
+        assert_eq!(boxes, 6);
+
+        assert_eq!(path(point), Some("TypeInfo::Object::3D::Placeable::Light::Point"));
+        assert_eq!(path(crate::ktid::ktid("TypeInfo::Object::3D::Placeable::Light").unwrap()), Some("TypeInfo::Object::3D::Placeable::Light"));
+        assert_eq!(path(KTID(0)), None);
+    }
+}
@@ -1,5 +1,12 @@
+//! KTIDs of the TypeInfos, generated from typeinfos.csv with `rdb_tool generate-typeinfos`.
+
 #![allow(dead_code)]
 
+mod lookup;
+mod registry;
+
+pub use lookup::*;
+
 pub mod object;
 pub mod rendernode;
 pub mod resource;
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(4223633138);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::AI::BehaviorTree::Manager`
+pub const ID: KTID = KTID(4223633138);
+pub const NAME: &str = "TypeInfo::Object::AI::BehaviorTree::Manager";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
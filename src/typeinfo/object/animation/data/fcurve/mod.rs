@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(3262904432);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Animation::Data::FCurve`
+pub const ID: KTID = KTID(3262904432);
+pub const NAME: &str = "TypeInfo::Object::Animation::Data::FCurve";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
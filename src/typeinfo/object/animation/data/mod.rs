@@ -1,3 +1,16 @@
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::Object::Animation::Data`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(3775062681);
+pub const NAME: &str = "TypeInfo::Object::Animation::Data";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod camera;
 pub mod fcurve;
 pub mod model;
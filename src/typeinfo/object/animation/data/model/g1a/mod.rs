@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(888335499);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Animation::Data::Model::G1A`
+pub const ID: KTID = KTID(888335499);
+pub const NAME: &str = "TypeInfo::Object::Animation::Data::Model::G1A";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,16 @@
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::Object::Animation`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(115496547);
+pub const NAME: &str = "TypeInfo::Object::Animation";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod data;
 pub mod rtrig;
 pub mod util;
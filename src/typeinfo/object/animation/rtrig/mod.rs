@@ -1 +1,14 @@
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::Object::Animation::RTRig`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(2933319505);
+pub const NAME: &str = "TypeInfo::Object::Animation::RTRig";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod validator;
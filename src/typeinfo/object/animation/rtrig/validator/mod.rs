@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(2047214495);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Animation::RTRig::Validator`
+pub const ID: KTID = KTID(2047214495);
+pub const NAME: &str = "TypeInfo::Object::Animation::RTRig::Validator";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(2022362315);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Animation::Util::AnimationParams`
+pub const ID: KTID = KTID(2022362315);
+pub const NAME: &str = "TypeInfo::Object::Animation::Util::AnimationParams";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
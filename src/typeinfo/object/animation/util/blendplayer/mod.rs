@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(2078083059);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Animation::Util::BlendPlayer`
+pub const ID: KTID = KTID(2078083059);
+pub const NAME: &str = "TypeInfo::Object::Animation::Util::BlendPlayer";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,16 @@
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::Object::Animation::Util`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(2552579521);
+pub const NAME: &str = "TypeInfo::Object::Animation::Util";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod animationparams;
 pub mod blendplayer;
 pub mod sequentialplayer;
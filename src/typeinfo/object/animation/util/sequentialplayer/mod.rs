@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(296243489);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Animation::Util::SequentialPlayer`
+pub const ID: KTID = KTID(296243489);
+pub const NAME: &str = "TypeInfo::Object::Animation::Util::SequentialPlayer";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(783782209);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Container::List::Array`
+pub const ID: KTID = KTID(783782209);
+pub const NAME: &str = "TypeInfo::Object::Container::List::Array";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
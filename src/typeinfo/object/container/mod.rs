@@ -1,2 +1,15 @@
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::Object::Container`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(1811649798);
+pub const NAME: &str = "TypeInfo::Object::Container";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod list;
 pub mod stack;
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(3140047982);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Container::Stack`
+pub const ID: KTID = KTID(3140047982);
+pub const NAME: &str = "TypeInfo::Object::Container::Stack";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(3405547762);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::DopeSheet::ApplyAnimation`
+pub const ID: KTID = KTID(3405547762);
+pub const NAME: &str = "TypeInfo::Object::DopeSheet::ApplyAnimation";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(2032182262);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::DopeSheet::AutomatonEntryPoint`
+pub const ID: KTID = KTID(2032182262);
+pub const NAME: &str = "TypeInfo::Object::DopeSheet::AutomatonEntryPoint";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(2915261347);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::DopeSheet::Camera`
+pub const ID: KTID = KTID(2915261347);
+pub const NAME: &str = "TypeInfo::Object::DopeSheet::Camera";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(2845702711);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::DopeSheet::Effect`
+pub const ID: KTID = KTID(2845702711);
+pub const NAME: &str = "TypeInfo::Object::DopeSheet::Effect";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(1636778580);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::DopeSheet::EmptyPlaceable`
+pub const ID: KTID = KTID(1636778580);
+pub const NAME: &str = "TypeInfo::Object::DopeSheet::EmptyPlaceable";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(2705917347);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::DopeSheet::EnqProperties`
+pub const ID: KTID = KTID(2705917347);
+pub const NAME: &str = "TypeInfo::Object::DopeSheet::EnqProperties";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
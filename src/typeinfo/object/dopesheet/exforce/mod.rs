@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(4110389472);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::DopeSheet::ExForce`
+pub const ID: KTID = KTID(4110389472);
+pub const NAME: &str = "TypeInfo::Object::DopeSheet::ExForce";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
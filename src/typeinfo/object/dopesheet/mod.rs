@@ -1,3 +1,16 @@
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::Object::DopeSheet`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(2356734792);
+pub const NAME: &str = "TypeInfo::Object::DopeSheet";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod applyanimation;
 pub mod automatonentrypoint;
 pub mod camera;
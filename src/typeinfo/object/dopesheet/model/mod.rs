@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(266352081);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::DopeSheet::Model`
+pub const ID: KTID = KTID(266352081);
+pub const NAME: &str = "TypeInfo::Object::DopeSheet::Model";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,14 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(4106424914);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::DopeSheet::RenderParts`
+pub const ID: KTID = KTID(4106424914);
+pub const NAME: &str = "TypeInfo::Object::DopeSheet::RenderParts";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
+pub mod partsset;
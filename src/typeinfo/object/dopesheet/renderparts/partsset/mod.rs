@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(2827019540);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::DopeSheet::RenderParts::PartsSet`
+pub const ID: KTID = KTID(2827019540);
+pub const NAME: &str = "TypeInfo::Object::DopeSheet::RenderParts::PartsSet";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(1159668238);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::DopeSheet::SimpleWind`
+pub const ID: KTID = KTID(1159668238);
+pub const NAME: &str = "TypeInfo::Object::DopeSheet::SimpleWind";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(3623897719);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::DopeSheet::Sound`
+pub const ID: KTID = KTID(3623897719);
+pub const NAME: &str = "TypeInfo::Object::DopeSheet::Sound";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(1812112092);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::DopeSheet::SpotLight`
+pub const ID: KTID = KTID(1812112092);
+pub const NAME: &str = "TypeInfo::Object::DopeSheet::SpotLight";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(3341757630);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::DopeSheet::SpotWind`
+pub const ID: KTID = KTID(3341757630);
+pub const NAME: &str = "TypeInfo::Object::DopeSheet::SpotWind";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
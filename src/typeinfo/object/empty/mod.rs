@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(4059824058);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Empty`
+pub const ID: KTID = KTID(4059824058);
+pub const NAME: &str = "TypeInfo::Object::Empty";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
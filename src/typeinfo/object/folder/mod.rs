@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(1661100949);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Folder`
+pub const ID: KTID = KTID(1661100949);
+pub const NAME: &str = "TypeInfo::Object::Folder";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
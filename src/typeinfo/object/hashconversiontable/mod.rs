@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(707505469);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::HashConversionTable`
+pub const ID: KTID = KTID(707505469);
+pub const NAME: &str = "TypeInfo::Object::HashConversionTable";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
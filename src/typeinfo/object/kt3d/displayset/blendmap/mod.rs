@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(3119423843);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::3D::Displayset::BlendMap`
+pub const ID: KTID = KTID(3119423843);
+pub const NAME: &str = "TypeInfo::Object::3D::Displayset::BlendMap";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,16 @@
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::Object::3D::Fog`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(1337713562);
+pub const NAME: &str = "TypeInfo::Object::3D::Fog";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod distance;
 pub mod scattering;
 pub mod threepoints;
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(2566977140);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::3D::LayeredBRDFLayers`
+pub const ID: KTID = KTID(2566977140);
+pub const NAME: &str = "TypeInfo::Object::3D::LayeredBRDFLayers";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,16 @@
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::Object::3D`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(241433848);
+pub const NAME: &str = "TypeInfo::Object::3D";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod blendmapnoisetexture;
 pub mod blendmapplacement;
 pub mod displayset;
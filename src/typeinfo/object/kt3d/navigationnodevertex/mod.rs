@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(1712059922);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::3D::NavigationNodeVertex`
+pub const ID: KTID = KTID(1712059922);
+pub const NAME: &str = "TypeInfo::Object::3D::NavigationNodeVertex";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,16 @@
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::Object::3D::Placeable::Camera`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(3475358386);
+pub const NAME: &str = "TypeInfo::Object::3D::Placeable::Camera";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod ortho;
 pub mod perspective;
 pub mod reference;
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(1043719434);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::3D::Placeable::Camera::Ortho`
+pub const ID: KTID = KTID(1043719434);
+pub const NAME: &str = "TypeInfo::Object::3D::Placeable::Camera::Ortho";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
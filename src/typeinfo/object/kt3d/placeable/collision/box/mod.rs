@@ -1,3 +1,19 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(1035581534);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::3D::Placeable::Collision::Box`
+pub const ID: KTID = KTID(1035581534);
+pub const NAME: &str = "TypeInfo::Object::3D::Placeable::Collision::Box";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
+pub mod soundemitterbox;
+pub mod soundgatebox;
+pub mod soundoccluderbox;
+pub mod soundportalbox;
+pub mod soundstreamemitterbox;
+pub mod soundswitchbox;
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(4077569238);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::3D::Placeable::Collision::Box::SoundSwitchBox`
+pub const ID: KTID = KTID(4077569238);
+pub const NAME: &str = "TypeInfo::Object::3D::Placeable::Collision::Box::SoundSwitchBox";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
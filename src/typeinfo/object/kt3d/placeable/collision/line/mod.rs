@@ -1,3 +1,15 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(1256704701);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::3D::Placeable::Collision::Line`
+pub const ID: KTID = KTID(1256704701);
+pub const NAME: &str = "TypeInfo::Object::3D::Placeable::Collision::Line";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
+pub mod soundemitterline;
+pub mod soundstreamemitterline;
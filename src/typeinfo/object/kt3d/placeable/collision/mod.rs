@@ -1,3 +1,16 @@
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::Object::3D::Placeable::Collision`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(871943657);
+pub const NAME: &str = "TypeInfo::Object::3D::Placeable::Collision";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod r#box;
 pub mod capsule;
 pub mod line;
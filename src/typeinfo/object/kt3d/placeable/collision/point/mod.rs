@@ -1,3 +1,15 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(1298361721);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::3D::Placeable::Collision::Point`
+pub const ID: KTID = KTID(1298361721);
+pub const NAME: &str = "TypeInfo::Object::3D::Placeable::Collision::Point";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
+pub mod soundemitterpoint;
+pub mod soundstreamemitterpoint;
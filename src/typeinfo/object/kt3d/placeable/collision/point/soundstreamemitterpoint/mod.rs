@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(803936938);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::3D::Placeable::Collision::Point::SoundStreamEmitterPoint`
+pub const ID: KTID = KTID(803936938);
+pub const NAME: &str = "TypeInfo::Object::3D::Placeable::Collision::Point::SoundStreamEmitterPoint";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,18 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(266229608);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::3D::Placeable::Collision::Prism`
+pub const ID: KTID = KTID(266229608);
+pub const NAME: &str = "TypeInfo::Object::3D::Placeable::Collision::Prism";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
+pub mod soundemitterprism;
+pub mod soundgateprism;
+pub mod soundoccluderprism;
+pub mod soundstreamemitterprism;
+pub mod soundswitchprism;
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(387886703);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::3D::Placeable::Collision::Prism::SoundOccluderPrism`
+pub const ID: KTID = KTID(387886703);
+pub const NAME: &str = "TypeInfo::Object::3D::Placeable::Collision::Prism::SoundOccluderPrism";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
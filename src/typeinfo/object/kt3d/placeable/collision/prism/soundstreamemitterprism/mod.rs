@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(2478298506);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::3D::Placeable::Collision::Prism::SoundStreamEmitterPrism`
+pub const ID: KTID = KTID(2478298506);
+pub const NAME: &str = "TypeInfo::Object::3D::Placeable::Collision::Prism::SoundStreamEmitterPrism";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
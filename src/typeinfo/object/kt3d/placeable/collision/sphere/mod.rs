@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(2083692950);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::3D::Placeable::Collision::Sphere`
+pub const ID: KTID = KTID(2083692950);
+pub const NAME: &str = "TypeInfo::Object::3D::Placeable::Collision::Sphere";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
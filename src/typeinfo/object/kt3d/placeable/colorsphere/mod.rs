@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(3913201351);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::3D::Placeable::ColorSphere`
+pub const ID: KTID = KTID(3913201351);
+pub const NAME: &str = "TypeInfo::Object::3D::Placeable::ColorSphere";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1 +1,14 @@
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::Object::3D::Placeable::Effect`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(145160006);
+pub const NAME: &str = "TypeInfo::Object::3D::Placeable::Effect";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod worldpq;
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(2068516036);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::3D::Placeable::Empty`
+pub const ID: KTID = KTID(2068516036);
+pub const NAME: &str = "TypeInfo::Object::3D::Placeable::Empty";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
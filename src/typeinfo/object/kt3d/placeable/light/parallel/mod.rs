@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(3759539956);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::3D::Placeable::Light::Parallel`
+pub const ID: KTID = KTID(3759539956);
+pub const NAME: &str = "TypeInfo::Object::3D::Placeable::Light::Parallel";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
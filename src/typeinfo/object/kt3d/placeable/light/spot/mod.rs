@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(760061743);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::3D::Placeable::Light::Spot`
+pub const ID: KTID = KTID(760061743);
+pub const NAME: &str = "TypeInfo::Object::3D::Placeable::Light::Spot";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(4030844514);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::3D::Placeable::Location`
+pub const ID: KTID = KTID(4030844514);
+pub const NAME: &str = "TypeInfo::Object::3D::Placeable::Location";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1 +1,14 @@
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::Object::3D::Placeable::Sound`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(2699503494);
+pub const NAME: &str = "TypeInfo::Object::3D::Placeable::Sound";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod soundeffect;
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(2652939330);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::3D::Placeable::SpriteWindow`
+pub const ID: KTID = KTID(2652939330);
+pub const NAME: &str = "TypeInfo::Object::3D::Placeable::SpriteWindow";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
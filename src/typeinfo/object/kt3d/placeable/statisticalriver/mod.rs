@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(3738823750);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::3D::Placeable::StatisticalRiver`
+pub const ID: KTID = KTID(3738823750);
+pub const NAME: &str = "TypeInfo::Object::3D::Placeable::StatisticalRiver";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,2 +1,15 @@
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::Object::3D::Placeable::Terrain`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(2494696364);
+pub const NAME: &str = "TypeInfo::Object::3D::Placeable::Terrain";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod refstatic;
 pub mod r#static;
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(2112514618);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::3D::Placeable::Terrain::Static`
+pub const ID: KTID = KTID(2112514618);
+pub const NAME: &str = "TypeInfo::Object::3D::Placeable::Terrain::Static";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
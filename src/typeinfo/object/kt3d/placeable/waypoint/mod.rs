@@ -1,2 +1,15 @@
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::Object::3D::Placeable::Waypoint`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(387616854);
+pub const NAME: &str = "TypeInfo::Object::3D::Placeable::Waypoint";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod edge;
 pub mod vertex;
@@ -1,3 +1,16 @@
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::Object::3D::Placeable::Wind`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(2812106799);
+pub const NAME: &str = "TypeInfo::Object::3D::Placeable::Wind";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod area;
 pub mod point;
 pub mod spot;
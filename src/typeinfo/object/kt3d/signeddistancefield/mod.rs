@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(1907518511);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::3D::SignedDistanceField`
+pub const ID: KTID = KTID(1907518511);
+pub const NAME: &str = "TypeInfo::Object::3D::SignedDistanceField";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
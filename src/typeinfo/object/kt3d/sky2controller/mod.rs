@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(314413445);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::3D::Sky2Controller`
+pub const ID: KTID = KTID(314413445);
+pub const NAME: &str = "TypeInfo::Object::3D::Sky2Controller";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(447067742);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::3D::StaticSky2AtmoTable`
+pub const ID: KTID = KTID(447067742);
+pub const NAME: &str = "TypeInfo::Object::3D::StaticSky2AtmoTable";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,14 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(2544838397);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::3D::View`
+pub const ID: KTID = KTID(2544838397);
+pub const NAME: &str = "TypeInfo::Object::3D::View";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
+pub mod commonviewsetting;
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(325676409);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Map::2D`
+pub const ID: KTID = KTID(325676409);
+pub const NAME: &str = "TypeInfo::Object::Map::2D";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
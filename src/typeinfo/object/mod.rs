@@ -1,4 +1,16 @@
-pub mod kt3d;
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::Object`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(1860022535);
+pub const NAME: &str = "TypeInfo::Object";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod ai;
 pub mod animation;
 pub mod container;
@@ -6,6 +18,7 @@ pub mod dopesheet;
 pub mod empty;
 pub mod folder;
 pub mod hashconversiontable;
+pub mod kt3d;
 pub mod map;
 pub mod motorcharactersetting;
 pub mod motorcommonsetting;
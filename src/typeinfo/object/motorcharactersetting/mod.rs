@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(3515272939);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::MotorCharacterSetting`
+pub const ID: KTID = KTID(3515272939);
+pub const NAME: &str = "TypeInfo::Object::MotorCharacterSetting";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
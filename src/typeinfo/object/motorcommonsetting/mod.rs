@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(3670610391);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::MotorCommonSetting`
+pub const ID: KTID = KTID(3670610391);
+pub const NAME: &str = "TypeInfo::Object::MotorCommonSetting";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
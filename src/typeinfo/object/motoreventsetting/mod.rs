@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(2441891932);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::MotorEventSetting`
+pub const ID: KTID = KTID(2441891932);
+pub const NAME: &str = "TypeInfo::Object::MotorEventSetting";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(2365803841);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::MotorScreenLayoutCommonSetting`
+pub const ID: KTID = KTID(2365803841);
+pub const NAME: &str = "TypeInfo::Object::MotorScreenLayoutCommonSetting";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
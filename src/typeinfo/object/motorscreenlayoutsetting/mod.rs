@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(2712162092);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::MotorScreenLayoutSetting`
+pub const ID: KTID = KTID(2712162092);
+pub const NAME: &str = "TypeInfo::Object::MotorScreenLayoutSetting";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
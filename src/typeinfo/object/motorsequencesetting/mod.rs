@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(1474100161);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::MotorSequenceSetting`
+pub const ID: KTID = KTID(1474100161);
+pub const NAME: &str = "TypeInfo::Object::MotorSequenceSetting";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(3788768768);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::MotorStageSetting`
+pub const ID: KTID = KTID(3788768768);
+pub const NAME: &str = "TypeInfo::Object::MotorStageSetting";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(480611232);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::MotorStageVisibilitySet`
+pub const ID: KTID = KTID(480611232);
+pub const NAME: &str = "TypeInfo::Object::MotorStageVisibilitySet";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(2183062599);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Param::Frame`
+pub const ID: KTID = KTID(2183062599);
+pub const NAME: &str = "TypeInfo::Object::Param::Frame";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,16 @@
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::Object::Param`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(168761018);
+pub const NAME: &str = "TypeInfo::Object::Param";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod frame;
 pub mod parallel;
 pub mod posteffect;
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(3990521075);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Param::Parallel`
+pub const ID: KTID = KTID(3990521075);
+pub const NAME: &str = "TypeInfo::Object::Param::Parallel";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(860003881);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Param::PostEffect`
+pub const ID: KTID = KTID(860003881);
+pub const NAME: &str = "TypeInfo::Object::Param::PostEffect";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
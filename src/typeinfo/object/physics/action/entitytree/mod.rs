@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(3163224009);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Physics::Action::EntityTree`
+pub const ID: KTID = KTID(3163224009);
+pub const NAME: &str = "TypeInfo::Object::Physics::Action::EntityTree";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,16 @@
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::Object::Physics`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(1252692224);
+pub const NAME: &str = "TypeInfo::Object::Physics";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod action;
 pub mod physdata;
 pub mod scene;
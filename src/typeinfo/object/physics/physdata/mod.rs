@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(1370947716);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Physics::PhysData`
+pub const ID: KTID = KTID(1370947716);
+pub const NAME: &str = "TypeInfo::Object::Physics::PhysData";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(3904969836);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Physics::Scene`
+pub const ID: KTID = KTID(3904969836);
+pub const NAME: &str = "TypeInfo::Object::Physics::Scene";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
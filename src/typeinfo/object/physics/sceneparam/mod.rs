@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(2713320479);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Physics::SceneParam`
+pub const ID: KTID = KTID(2713320479);
+pub const NAME: &str = "TypeInfo::Object::Physics::SceneParam";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(2619141379);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Render::FontParameter`
+pub const ID: KTID = KTID(2619141379);
+pub const NAME: &str = "TypeInfo::Object::Render::FontParameter";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
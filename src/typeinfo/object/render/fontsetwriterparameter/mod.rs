@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(3288683168);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Render::FontsetWriterParameter`
+pub const ID: KTID = KTID(3288683168);
+pub const NAME: &str = "TypeInfo::Object::Render::FontsetWriterParameter";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
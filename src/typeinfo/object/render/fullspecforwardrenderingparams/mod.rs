@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(1734140308);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Render::FullSpecForwardRenderingParams`
+pub const ID: KTID = KTID(1734140308);
+pub const NAME: &str = "TypeInfo::Object::Render::FullSpecForwardRenderingParams";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
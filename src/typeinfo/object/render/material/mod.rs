@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(2834383876);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Render::Material`
+pub const ID: KTID = KTID(2834383876);
+pub const NAME: &str = "TypeInfo::Object::Render::Material";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
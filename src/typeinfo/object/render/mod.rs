@@ -1,3 +1,16 @@
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::Object::Render`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(3450490429);
+pub const NAME: &str = "TypeInfo::Object::Render";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod fontparameter;
 pub mod fontsetwriterparameter;
 pub mod fullspecforwardrenderingparams;
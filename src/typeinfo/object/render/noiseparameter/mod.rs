@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(278179532);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Render::NoiseParameter`
+pub const ID: KTID = KTID(278179532);
+pub const NAME: &str = "TypeInfo::Object::Render::NoiseParameter";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(2204235796);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Render::OffScreenRenderingParams`
+pub const ID: KTID = KTID(2204235796);
+pub const NAME: &str = "TypeInfo::Object::Render::OffScreenRenderingParams";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
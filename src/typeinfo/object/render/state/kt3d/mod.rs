@@ -1,2 +1,15 @@
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::Object::Render::State::3D`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(825952475);
+pub const NAME: &str = "TypeInfo::Object::Render::State::3D";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod shader;
 pub mod textures;
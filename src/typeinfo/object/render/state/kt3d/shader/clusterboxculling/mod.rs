@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(46765013);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Render::State::3D::Shader::ClusterBoxCulling`
+pub const ID: KTID = KTID(46765013);
+pub const NAME: &str = "TypeInfo::Object::Render::State::3D::Shader::ClusterBoxCulling";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
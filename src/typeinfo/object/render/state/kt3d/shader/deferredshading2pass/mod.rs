@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(2256996446);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Render::State::3D::Shader::DeferredShading2Pass`
+pub const ID: KTID = KTID(2256996446);
+pub const NAME: &str = "TypeInfo::Object::Render::State::3D::Shader::DeferredShading2Pass";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(3272421685);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Render::State::3D::Shader::Effect2Outline`
+pub const ID: KTID = KTID(3272421685);
+pub const NAME: &str = "TypeInfo::Object::Render::State::3D::Shader::Effect2Outline";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
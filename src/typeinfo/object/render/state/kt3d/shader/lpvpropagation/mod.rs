@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(1784356124);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Render::State::3D::Shader::LPVPropagation`
+pub const ID: KTID = KTID(1784356124);
+pub const NAME: &str = "TypeInfo::Object::Render::State::3D::Shader::LPVPropagation";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
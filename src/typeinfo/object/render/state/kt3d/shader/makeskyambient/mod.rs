@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(2305046001);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Render::State::3D::Shader::MakeSkyAmbient`
+pub const ID: KTID = KTID(2305046001);
+pub const NAME: &str = "TypeInfo::Object::Render::State::3D::Shader::MakeSkyAmbient";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
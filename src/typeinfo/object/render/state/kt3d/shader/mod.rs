@@ -1,3 +1,16 @@
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::Object::Render::State::3D::Shader`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(2915245750);
+pub const NAME: &str = "TypeInfo::Object::Render::State::3D::Shader";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod anisotropicstandard;
 pub mod aoraytrace;
 pub mod applyssao;
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(3220179270);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Render::State::3D::Shader::NDF`
+pub const ID: KTID = KTID(3220179270);
+pub const NAME: &str = "TypeInfo::Object::Render::State::3D::Shader::NDF";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
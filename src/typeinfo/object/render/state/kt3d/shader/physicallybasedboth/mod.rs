@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(3038493990);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Render::State::3D::Shader::PhysicallyBasedBoth`
+pub const ID: KTID = KTID(3038493990);
+pub const NAME: &str = "TypeInfo::Object::Render::State::3D::Shader::PhysicallyBasedBoth";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(3754816875);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Render::State::3D::Shader::Resolve4KGeometry`
+pub const ID: KTID = KTID(3754816875);
+pub const NAME: &str = "TypeInfo::Object::Render::State::3D::Shader::Resolve4KGeometry";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
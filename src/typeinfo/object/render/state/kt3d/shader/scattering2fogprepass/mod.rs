@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(1386832890);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Render::State::3D::Shader::Scattering2FogPrePass`
+pub const ID: KTID = KTID(1386832890);
+pub const NAME: &str = "TypeInfo::Object::Render::State::3D::Shader::Scattering2FogPrePass";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
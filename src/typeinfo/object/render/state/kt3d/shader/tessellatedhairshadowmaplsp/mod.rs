@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(2217934443);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Render::State::3D::Shader::TessellatedHairShadowmapLSP`
+pub const ID: KTID = KTID(2217934443);
+pub const NAME: &str = "TypeInfo::Object::Render::State::3D::Shader::TessellatedHairShadowmapLSP";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
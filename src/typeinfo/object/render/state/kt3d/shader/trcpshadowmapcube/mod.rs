@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(1071390620);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Render::State::3D::Shader::TRCPShadowmapCube`
+pub const ID: KTID = KTID(1071390620);
+pub const NAME: &str = "TypeInfo::Object::Render::State::3D::Shader::TRCPShadowmapCube";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
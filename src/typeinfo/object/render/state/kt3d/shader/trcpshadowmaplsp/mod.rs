@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(777776638);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Render::State::3D::Shader::TRCPShadowmapLSP`
+pub const ID: KTID = KTID(777776638);
+pub const NAME: &str = "TypeInfo::Object::Render::State::3D::Shader::TRCPShadowmapLSP";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
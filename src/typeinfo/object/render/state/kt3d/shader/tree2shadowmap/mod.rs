@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(2588211918);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Render::State::3D::Shader::Tree2Shadowmap`
+pub const ID: KTID = KTID(2588211918);
+pub const NAME: &str = "TypeInfo::Object::Render::State::3D::Shader::Tree2Shadowmap";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(575153655);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Render::State::3D::Shader::Tree2ShadowmapLSP`
+pub const ID: KTID = KTID(575153655);
+pub const NAME: &str = "TypeInfo::Object::Render::State::3D::Shader::Tree2ShadowmapLSP";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
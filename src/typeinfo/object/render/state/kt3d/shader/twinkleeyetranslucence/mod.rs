@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(2691100868);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Render::State::3D::Shader::TwinkleEyeTranslucence`
+pub const ID: KTID = KTID(2691100868);
+pub const NAME: &str = "TypeInfo::Object::Render::State::3D::Shader::TwinkleEyeTranslucence";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
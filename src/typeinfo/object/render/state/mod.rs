@@ -1,3 +1,16 @@
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::Object::Render::State`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(1999557868);
+pub const NAME: &str = "TypeInfo::Object::Render::State";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod kt3d;
 pub mod material;
 pub mod mrt;
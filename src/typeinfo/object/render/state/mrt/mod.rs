@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(1908735483);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Render::State::MRT`
+pub const ID: KTID = KTID(1908735483);
+pub const NAME: &str = "TypeInfo::Object::Render::State::MRT";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(2040077945);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Render::StaticScreenLayoutTextures`
+pub const ID: KTID = KTID(2040077945);
+pub const NAME: &str = "TypeInfo::Object::Render::StaticScreenLayoutTextures";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(1532027545);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Render::Texture::IES`
+pub const ID: KTID = KTID(1532027545);
+pub const NAME: &str = "TypeInfo::Object::Render::Texture::IES";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
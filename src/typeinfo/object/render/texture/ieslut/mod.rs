@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(3611565646);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Render::Texture::IESLut`
+pub const ID: KTID = KTID(3611565646);
+pub const NAME: &str = "TypeInfo::Object::Render::Texture::IESLut";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
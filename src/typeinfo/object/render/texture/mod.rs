@@ -1,3 +1,16 @@
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::Object::Render::Texture`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(3060988578);
+pub const NAME: &str = "TypeInfo::Object::Render::Texture";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod ies;
 pub mod ieslut;
 pub mod rendertarget;
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(3626443412);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Render::Texture::RenderTarget::Array`
+pub const ID: KTID = KTID(3626443412);
+pub const NAME: &str = "TypeInfo::Object::Render::Texture::RenderTarget::Array";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(469693977);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Render::Texture::RenderTarget::DefaultColor`
+pub const ID: KTID = KTID(469693977);
+pub const NAME: &str = "TypeInfo::Object::Render::Texture::RenderTarget::DefaultColor";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(4089250169);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Render::Texture::RenderTarget::DefaultDepth`
+pub const ID: KTID = KTID(4089250169);
+pub const NAME: &str = "TypeInfo::Object::Render::Texture::RenderTarget::DefaultDepth";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
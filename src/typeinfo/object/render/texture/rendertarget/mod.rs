@@ -1,3 +1,16 @@
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::Object::Render::Texture::RenderTarget`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(1348009371);
+pub const NAME: &str = "TypeInfo::Object::Render::Texture::RenderTarget";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod array;
 pub mod cube;
 pub mod cubearray;
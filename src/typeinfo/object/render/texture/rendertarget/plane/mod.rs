@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(1914912279);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Render::Texture::RenderTarget::Plane`
+pub const ID: KTID = KTID(1914912279);
+pub const NAME: &str = "TypeInfo::Object::Render::Texture::RenderTarget::Plane";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(4286431188);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Render::Texture::Static`
+pub const ID: KTID = KTID(4286431188);
+pub const NAME: &str = "TypeInfo::Object::Render::Texture::Static";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
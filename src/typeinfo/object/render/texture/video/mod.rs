@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(3150430013);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Render::Texture::Video`
+pub const ID: KTID = KTID(3150430013);
+pub const NAME: &str = "TypeInfo::Object::Render::Texture::Video";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(3143419271);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::ResourceKeeper`
+pub const ID: KTID = KTID(3143419271);
+pub const NAME: &str = "TypeInfo::Object::ResourceKeeper";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(3772288575);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Script::Code`
+pub const ID: KTID = KTID(3772288575);
+pub const NAME: &str = "TypeInfo::Object::Script::Code";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
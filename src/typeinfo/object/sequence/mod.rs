@@ -1,3 +1,16 @@
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::Object::Sequence`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(2037255912);
+pub const NAME: &str = "TypeInfo::Object::Sequence";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod sequenceenginecallback;
 pub mod sequencemanager;
 pub mod sequencevariable;
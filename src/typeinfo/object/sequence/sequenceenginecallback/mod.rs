@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(2178938768);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Sequence::SequenceEngineCallback`
+pub const ID: KTID = KTID(2178938768);
+pub const NAME: &str = "TypeInfo::Object::Sequence::SequenceEngineCallback";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
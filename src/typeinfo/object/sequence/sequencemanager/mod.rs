@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(3800292380);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Sequence::SequenceManager`
+pub const ID: KTID = KTID(3800292380);
+pub const NAME: &str = "TypeInfo::Object::Sequence::SequenceManager";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(2745381605);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Sequence::SequenceVariable`
+pub const ID: KTID = KTID(2745381605);
+pub const NAME: &str = "TypeInfo::Object::Sequence::SequenceVariable";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
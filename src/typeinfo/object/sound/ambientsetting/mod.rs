@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(2477992448);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Sound::AmbientSetting`
+pub const ID: KTID = KTID(2477992448);
+pub const NAME: &str = "TypeInfo::Object::Sound::AmbientSetting";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
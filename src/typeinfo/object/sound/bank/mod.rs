@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(1512196124);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Sound::Bank`
+pub const ID: KTID = KTID(1512196124);
+pub const NAME: &str = "TypeInfo::Object::Sound::Bank";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(3370109483);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Sound::GlobalConfiguration`
+pub const ID: KTID = KTID(3370109483);
+pub const NAME: &str = "TypeInfo::Object::Sound::GlobalConfiguration";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
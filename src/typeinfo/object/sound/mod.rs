@@ -1,3 +1,16 @@
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::Object::Sound`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(3982096696);
+pub const NAME: &str = "TypeInfo::Object::Sound";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod ambientsetting;
 pub mod bank;
 pub mod globalconfiguration;
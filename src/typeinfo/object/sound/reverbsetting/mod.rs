@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(1562081974);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Sound::ReverbSetting`
+pub const ID: KTID = KTID(1562081974);
+pub const NAME: &str = "TypeInfo::Object::Sound::ReverbSetting";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
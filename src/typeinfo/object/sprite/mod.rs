@@ -1,2 +1,15 @@
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::Object::Sprite`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(3666939884);
+pub const NAME: &str = "TypeInfo::Object::Sprite";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod texpatternanimdata;
 pub mod textdata;
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(4156608752);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Sprite::TexPatternAnimData`
+pub const ID: KTID = KTID(4156608752);
+pub const NAME: &str = "TypeInfo::Object::Sprite::TexPatternAnimData";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(3821481091);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Sprite::TextData`
+pub const ID: KTID = KTID(3821481091);
+pub const NAME: &str = "TypeInfo::Object::Sprite::TextData";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
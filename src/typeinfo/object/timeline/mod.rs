@@ -1 +1,14 @@
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::Object::Timeline`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(968046120);
+pub const NAME: &str = "TypeInfo::Object::Timeline";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod script;
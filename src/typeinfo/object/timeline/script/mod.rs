@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(239008595);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Timeline::Script`
+pub const ID: KTID = KTID(239008595);
+pub const NAME: &str = "TypeInfo::Object::Timeline::Script";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
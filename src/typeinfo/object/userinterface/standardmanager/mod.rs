@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(864599817);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::UserInterface::StandardManager`
+pub const ID: KTID = KTID(864599817);
+pub const NAME: &str = "TypeInfo::Object::UserInterface::StandardManager";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(3326083179);
\ No newline at end of file
+/// KTID of `TypeInfo::Object::Video::Streamset::MotorFilePathBased`
+pub const ID: KTID = KTID(3326083179);
+pub const NAME: &str = "TypeInfo::Object::Video::Streamset::MotorFilePathBased";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -0,0 +1,432 @@
+// Generated from typeinfos.csv with `rdb_tool generate-typeinfos`, do not edit by hand.
+
+/// Every known TypeInfo as (KTID, kind, full type path), sorted by KTID
+pub(super) static TYPEINFOS: &[(u32, &str, &str)] = &[
+    (0x005ee192, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::CurvatureSkin"),
+    (0x0118e31a, "TypeInfo", "TypeInfo::Object::3D::Displayset::TrianglesEx"),
+    (0x01818d62, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::GenerateFilteredMipMap"),
+    (0x02337fc5, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::PhysicallyBasedBothHighPrioRefraction"),
+    (0x0296e56b, "TypeInfo", "TypeInfo::TaskNode::View::3D::EndRegisterLightAndBeginRegisterPrimitive"),
+    (0x02c993d5, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::ClusterBoxCulling"),
+    (0x03c0dfa9, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::PhysicallyBasedTRCPMetal"),
+    (0x03dc8628, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::SSAO"),
+    (0x03e7b957, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::ReflectiveShadowmap"),
+    (0x04a1ba99, "TypeInfo", "TypeInfo::RenderNode::3D::TileLightCulling"),
+    (0x04c49e64, "TypeInfo", "TypeInfo::Object::3D::Placeable::Decal"),
+    (0x04f7dd1f, "TypeInfo", "TypeInfo::Object::Render::Texture::RenderTarget::CubeArray"),
+    (0x06999d8d, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::AORayTrace"),
+    (0x075f04b0, "TypeInfo", "TypeInfo::Object::3D::Placeable::Collision::Line::SoundStreamEmitterLine"),
+    (0x07b0b080, "TypeInfo", "TypeInfo::Object::3D::PeriodicEmissionParams"),
+    (0x087e8010, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::BMRayTrace"),
+    (0x09f32924, "TypeInfo", "TypeInfo::Object::3D::TextureArrayInfo"),
+    (0x0a20a15d, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::LightPass"),
+    (0x0ab9b5dd, "TypeInfo", "TypeInfo::Object::3D::Placeable::Light::Point"),
+    (0x0e3c3980, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::TRFRShadowmapGeneral"),
+    (0x0e3efb53, "TypeInfo", "TypeInfo::Object::Timeline::Script"),
+    (0x0eee9064, "TypeInfo", "TypeInfo::RenderNode::3D::MergeNatureCloudAndPatternMap"),
+    (0x0f191ad7, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::Effect2"),
+    (0x0fa8f324, "TypeInfo", "TypeInfo::TaskNode::Physics::Simulate"),
+    (0x0fde5768, "TypeInfo", "TypeInfo::Object::3D::Placeable::Collision::Prism"),
+    (0x0fe035d1, "TypeInfo", "TypeInfo::Object::DopeSheet::Model"),
+    (0x1015d17c, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::TRBRShadowmapGeneral"),
+    (0x104c083f, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::TRFRShadowmapLSP"),
+    (0x1081c881, "TypeInfo", "TypeInfo::Object::3D::Placeable::StaticScreenLayoutScrollView"),
+    (0x1094aecc, "TypeInfo", "TypeInfo::Object::Render::NoiseParameter"),
+    (0x11a85121, "TypeInfo", "TypeInfo::Object::Animation::Util::SequentialPlayer"),
+    (0x1225a03b, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::TRBRShadowmapLSP"),
+    (0x1238feec, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::TRFR"),
+    (0x12bd9185, "TypeInfo", "TypeInfo::Object::3D::Sky2Controller"),
+    (0x12ea4048, "TypeInfo", "TypeInfo::Object::3D::ScreenMap"),
+    (0x13696d79, "TypeInfo", "TypeInfo::Object::Map::2D"),
+    (0x141296e8, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::TRBR"),
+    (0x159397a4, "TypeInfo", "TypeInfo::Object::3D::BlendMapNoiseTexture"),
+    (0x15c4c84d, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::StreamWater"),
+    (0x162fb139, "TypeInfo", "TypeInfo::Object::Param::PostEffect3LensPreset"),
+    (0x1709f9d7, "TypeInfo", "TypeInfo::RenderNode::3D::NatureSky"),
+    (0x171eae6f, "TypeInfo", "TypeInfo::Object::3D::Placeable::Collision::Prism::SoundOccluderPrism"),
+    (0x186381f4, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::Hair"),
+    (0x18a58d8d, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::PhysicallyBasedTRCPStandard"),
+    (0x18eb1e56, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::PhysicallyBased2Standard"),
+    (0x197e61c6, "TypeInfo", "TypeInfo::RenderNode::3D::StaticNature"),
+    (0x19a646ce, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::CalmWater"),
+    (0x1a29a667, "TypeInfo", "TypeInfo::Object::3D::Placeable::CollisionVisualizer"),
+    (0x1a62db48, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::PhysicallyBasedTRFRMetal"),
+    (0x1aa5b65e, "TypeInfo", "TypeInfo::Object::3D::StaticSky2AtmoTable"),
+    (0x1bfef619, "TypeInfo", "TypeInfo::Object::Render::Texture::RenderTarget::DefaultColor"),
+    (0x1c8f048a, "TypeInfo", "TypeInfo::RenderNode::3D::StaticTerrain"),
+    (0x1ca58ba0, "TypeInfo", "TypeInfo::Object::MotorStageVisibilitySet"),
+    (0x1f7c5cf8, "TypeInfo", "TypeInfo::RenderNode::RenderTarget::EndScene"),
+    (0x1fccfa95, "TypeInfo", "TypeInfo::RenderNode::3D::Tree2Wind"),
+    (0x20a6a0bb, "TypeInfo", "TypeInfo::Resource::System::ObjectDatabaseFile"),
+    (0x20decbcc, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::PhysicallyBasedTRBRMetal"),
+    (0x210f2375, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::ConstantColor"),
+    (0x215594c2, "TypeInfo", "TypeInfo::Object::3D::Placeable::StaticScreenLayoutViewMask"),
+    (0x21cc39dd, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::TRFRShadowmapCube"),
+    (0x224353e1, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::TwinkleEye"),
+    (0x224825f7, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::Tree2ShadowmapLSP"),
+    (0x2287101b, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::PhysicallyBasedTRLCBoth"),
+    (0x23a5d1d9, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::TRBRShadowmapCube"),
+    (0x23e7c64e, "TypeInfo", "TypeInfo::Object::3D::Placeable::Camera::Perspective"),
+    (0x2448284c, "TypeInfo", "TypeInfo::Object::3D::StaticBlendMapPlacement"),
+    (0x24a7af0f, "TypeInfo", "TypeInfo::Object::3D::SkyAmbient"),
+    (0x2531ddea, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::PhysicallyBased2StreamWater"),
+    (0x253faf8a, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::SnowCover"),
+    (0x28916ad0, "TypeInfo", "TypeInfo::RenderNode::RenderTarget::HdrOutput"),
+    (0x28e7cd02, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::PhysicallyBasedFur"),
+    (0x2939b4b1, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::WriteDirectOcclusion"),
+    (0x29b5e2dd, "TypeInfo", "TypeInfo::Object::3D::OctreeManager"),
+    (0x2a2bad3d, "TypeInfo", "TypeInfo::Object::HashConversionTable"),
+    (0x2c012245, "TypeInfo", "TypeInfo::Object::3D::Placeable::Octree"),
+    (0x2c136e26, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::Ripple"),
+    (0x2c4c1f3f, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::TRCPShadowmapGeneral"),
+    (0x2d4d9f2f, "TypeInfo", "TypeInfo::Object::3D::Placeable::Light::Spot"),
+    (0x2e39c400, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::FluidSS"),
+    (0x2e5bedfe, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::TRCPShadowmapLSP"),
+    (0x2eb79141, "TypeInfo", "TypeInfo::Object::Container::List::Array"),
+    (0x2f47892c, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::PhysicallyBasedTRFRStandard"),
+    (0x2fb90c88, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::EffectDepth"),
+    (0x2feb1aaa, "TypeInfo", "TypeInfo::Object::3D::Placeable::Collision::Point::SoundStreamEmitterPoint"),
+    (0x30340faf, "TypeInfo", "TypeInfo::TaskNode::View::CommitToNextRender"),
+    (0x3048e4ab, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::TRCP"),
+    (0x3059b9c3, "TypeInfo", "TypeInfo::Object::Render::State::3D::Textures"),
+    (0x331497b2, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::PhysicallyBasedGrassStandard"),
+    (0x33429e29, "TypeInfo", "TypeInfo::Object::Param::PostEffect"),
+    (0x3388bf09, "TypeInfo", "TypeInfo::Object::UserInterface::StandardManager"),
+    (0x34f2ec8b, "TypeInfo", "TypeInfo::Object::Animation::Data::Model::G1A"),
+    (0x35c379b0, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::PhysicallyBasedTRBRStandard"),
+    (0x36ce880e, "TypeInfo", "TypeInfo::Object::3D::OcclusionCullingManager"),
+    (0x37a1bf05, "TypeInfo", "TypeInfo::Object::3D::RayTrace::PB"),
+    (0x37d6e4d3, "TypeInfo", "TypeInfo::Object::3D::Displayset::QuadrangleEx"),
+    (0x39e93192, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::Shadowmap"),
+    (0x3b6f1dab, "TypeInfo", "TypeInfo::Object::Animation::Data::Camera::G1A"),
+    (0x3bc0342d, "TypeInfo", "TypeInfo::Object::3D::Displayset::StreamWaterRiver"),
+    (0x3c00837e, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::PhysicallyBasedMetal"),
+    (0x3c0512c3, "TypeInfo", "TypeInfo::RenderNode::3D::VolumetricFogScatter"),
+    (0x3c6afed2, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::Tree2Wind"),
+    (0x3cbf21a2, "TypeInfo", "TypeInfo::Object::3D::Placeable::Wind::Area"),
+    (0x3db9b85e, "TypeInfo", "TypeInfo::Object::3D::Placeable::Collision::Box"),
+    (0x3e35e50a, "TypeInfo", "TypeInfo::Object::3D::Placeable::Camera::Ortho"),
+    (0x3ebd099e, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::TessellatedHair"),
+    (0x3ede08ef, "TypeInfo", "TypeInfo::Object::3D::Displayset::DestructionModel"),
+    (0x3fdc1f9c, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::TRCPShadowmapCube"),
+    (0x406cb6ff, "TypeInfo", "TypeInfo::RenderNode::3D::SSAO"),
+    (0x40b6cf64, "TypeInfo", "TypeInfo::Object::3D::Placeable::DecalRoad"),
+    (0x40ce2819, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::Tree2ShadowmapCube"),
+    (0x411b821e, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::TRLCShadowmapGeneralLSP"),
+    (0x41aeccbe, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::PhysicallyBased2TRLC"),
+    (0x41d80512, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::DeferredShadingPass"),
+    (0x4245a167, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::ShadowmapCube"),
+    (0x42c87d58, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::Outline"),
+    (0x42fe3e0d, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::TessellatedHairShadowmapCube"),
+    (0x44079ed0, "TypeInfo", "TypeInfo::TaskNode::Util::UpdateLevelEvent"),
+    (0x444bcd87, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::BlurPane"),
+    (0x44b44454, "TypeInfo", "TypeInfo::RenderNode::3D::ClusterDecalCulling"),
+    (0x451f220e, "TypeInfo", "TypeInfo::Object::DopeSheet::SimpleWind"),
+    (0x45207ea7, "TypeInfo", "TypeInfo::Object::3D::Displayset::ProjectionMapPlane"),
+    (0x46a0bfbc, "TypeInfo", "TypeInfo::RenderNode::3D::TerrainCopyToTextureAtlas"),
+    (0x47ed4578, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::RasterUV"),
+    (0x48570415, "TypeInfo", "TypeInfo::RenderNode::3D::StaticScreenLayout"),
+    (0x48755bbf, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::SDFUtility"),
+    (0x48e5fb2a, "TypeInfo", "TypeInfo::Object::3D::Placeable::Collision::Prism::SoundEmitterPrism"),
+    (0x48f274c8, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::Voxelize"),
+    (0x4a5a5c8c, "TypeInfo", "TypeInfo::Object::3D::Placeable::NavigationDataGenerator"),
+    (0x4a86223b, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::FurUtil"),
+    (0x4ae7cabd, "TypeInfo", "TypeInfo::Object::3D::Placeable::Collision::Line"),
+    (0x4b00da96, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::ConstantNoWriteZ"),
+    (0x4b35429e, "TypeInfo", "TypeInfo::Object::3D::RayTrace::BM"),
+    (0x4ca4c271, "TypeInfo", "TypeInfo::TaskNode::System::Empty"),
+    (0x4d636d79, "TypeInfo", "TypeInfo::Object::3D::Placeable::Collision::Point"),
+    (0x50606be4, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::VolumetricFogScatter"),
+    (0x51447b71, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::LPVInjection"),
+    (0x51b70084, "TypeInfo", "TypeInfo::Object::Physics::PhysData"),
+    (0x52a963fa, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::Scattering2FogPrePass"),
+    (0x53ce9cbd, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::TRLCShadowmap"),
+    (0x53dde8ae, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::SnowDeform"),
+    (0x557695f1, "TypeInfo", "TypeInfo::Object::Render::Texture::RenderTarget::CurrentDepth"),
+    (0x563d7a99, "TypeInfo", "TypeInfo::Object::Sound::StreamLayerSetting"),
+    (0x5642765c, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::CalmWater2"),
+    (0x56930c1c, "TypeInfo", "TypeInfo::TaskNode::Util::ApplicationCallback"),
+    (0x5694c18d, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::Tree2ShadowmapGeneralLSP"),
+    (0x5737efa3, "TypeInfo", "TypeInfo::Object::3D::Placeable::LocalAmbientSpecularMap"),
+    (0x5781f63b, "TypeInfo", "TypeInfo::Object::3D::RainDrop"),
+    (0x57dcfbc1, "TypeInfo", "TypeInfo::Object::MotorSequenceSetting"),
+    (0x5837b310, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::PhysicallyBasedTree2Both"),
+    (0x593a65d1, "TypeInfo", "TypeInfo::Object::Param::PostEffect3Lut"),
+    (0x59ec22d7, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::PhysicallyBasedDeferredDecal"),
+    (0x5a22481c, "TypeInfo", "TypeInfo::Object::Sound::Bank"),
+    (0x5b50e299, "TypeInfo", "TypeInfo::Object::Render::Texture::IES"),
+    (0x5b88333a, "TypeInfo", "TypeInfo::Object::3D::Placeable::Light::Area"),
+    (0x5bd49c74, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::OutlineAddition"),
+    (0x5c8b904b, "TypeInfo", "TypeInfo::RenderNode::RenderBlock::BeginCheckSkip"),
+    (0x5d1b7ab6, "TypeInfo", "TypeInfo::Object::Sound::ReverbSetting"),
+    (0x5d4a99d6, "TypeInfo", "TypeInfo::Object::3D::Fog::Scattering"),
+    (0x5e6109df, "TypeInfo", "TypeInfo::Object::3D::Placeable::OctreeUpdatedContainer"),
+    (0x5f0a8446, "TypeInfo", "TypeInfo::RenderNode::3D::ViewSetting"),
+    (0x5f98c084, "TypeInfo", "TypeInfo::Object::3D::Placeable::ProjectionMap"),
+    (0x618f4254, "TypeInfo", "TypeInfo::Object::DopeSheet::EmptyPlaceable"),
+    (0x61dbdf18, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::SnowCover2"),
+    (0x629c8799, "TypeInfo", "TypeInfo::Object::3D::Placeable::Waypoint::Edge"),
+    (0x63026395, "TypeInfo", "TypeInfo::Object::Folder"),
+    (0x63336873, "TypeInfo", "TypeInfo::Object::3D::Placeable::StaticScreenLayout"),
+    (0x63951c64, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::ObjectOcclusion"),
+    (0x63ba44b4, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::SSFog"),
+    (0x6426c3fe, "TypeInfo", "TypeInfo::Object::3D::BlendMapPlacement"),
+    (0x64d4aced, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::HeightFog"),
+    (0x65052a8a, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::TessellatedHairShadowmapGeneral"),
+    (0x658d05c8, "TypeInfo", "TypeInfo::Object::3D::ModelIllusionParams"),
+    (0x65d9dbe1, "TypeInfo", "TypeInfo::Object::3D::Placeable::Sequence::SequenceEmptyPlaceable"),
+    (0x660bf612, "TypeInfo", "TypeInfo::Object::3D::NavigationNodeVertex"),
+    (0x66ecb240, "TypeInfo", "TypeInfo::Object::3D::Placeable::Light::HDRI"),
+    (0x675ce194, "TypeInfo", "TypeInfo::Object::Render::FullSpecForwardRenderingParams"),
+    (0x6794ea8a, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::ShadowmapGeneral"),
+    (0x6a5a4cc1, "TypeInfo", "TypeInfo::Object::3D::Placeable::Collision::Prism::SoundGatePrism"),
+    (0x6a5b1d1c, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::LPVPropagation"),
+    (0x6af08512, "TypeInfo", "TypeInfo::Object::3D::Placeable::ScreenLayoutBlurPane"),
+    (0x6c01215e, "TypeInfo", "TypeInfo::Object::3D::ModelRenderPartsSetTypeContainer"),
+    (0x6c02a2dc, "TypeInfo", "TypeInfo::Object::DopeSheet::SpotLight"),
+    (0x6c0fc362, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::PhysicallyBasedStandard"),
+    (0x6cd2a1e9, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::TRFRShadowmapGeneralLSP"),
+    (0x6e817df7, "TypeInfo", "TypeInfo::RenderNode::3D::TiledDeferredShadingPass"),
+    (0x6eac39e5, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::TRBRShadowmapGeneralLSP"),
+    (0x70fd1685, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::PhysicallyBased2TRBR"),
+    (0x71b26c2f, "TypeInfo", "TypeInfo::Object::3D::SignedDistanceField"),
+    (0x71c4fdfb, "TypeInfo", "TypeInfo::Object::Render::State::MRT"),
+    (0x72233e17, "TypeInfo", "TypeInfo::Object::Render::Texture::RenderTarget::Plane"),
+    (0x73b95a31, "TypeInfo", "TypeInfo::Object::3D::SkylightDependingParams"),
+    (0x74b0f49c, "TypeInfo", "TypeInfo::Object::3D::VisibilitySetFlag"),
+    (0x750a3670, "TypeInfo", "TypeInfo::Object::3D::StaticGrass"),
+    (0x788acccb, "TypeInfo", "TypeInfo::Object::Animation::Util::AnimationParams"),
+    (0x7920a3f6, "TypeInfo", "TypeInfo::Object::DopeSheet::AutomatonEntryPoint"),
+    (0x79991e79, "TypeInfo", "TypeInfo::Object::Render::StaticScreenLayoutTextures"),
+    (0x79a26fa5, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::Effect"),
+    (0x7a06039f, "TypeInfo", "TypeInfo::Object::Animation::RTRig::Validator"),
+    (0x7aeb0b64, "TypeInfo", "TypeInfo::RenderNode::Video::Player"),
+    (0x7b4b0cc4, "TypeInfo", "TypeInfo::Object::3D::Placeable::Empty"),
+    (0x7bc602dd, "TypeInfo", "TypeInfo::Object::3D::Placeable::Camera::Reference"),
+    (0x7bdd07f3, "TypeInfo", "TypeInfo::Object::Animation::Util::BlendPlayer"),
+    (0x7c32a196, "TypeInfo", "TypeInfo::Object::3D::Placeable::Collision::Sphere"),
+    (0x7c80aa6d, "TypeInfo", "TypeInfo::Object::3D::Placeable::Collision::Box::SoundGateBox"),
+    (0x7d3a3254, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::PhysicallyBasedTree2Standard"),
+    (0x7d51e30d, "TypeInfo", "TypeInfo::Object::3D::Placeable::Wind::Spot"),
+    (0x7db88a91, "TypeInfo", "TypeInfo::Object::Render::Texture::RenderTarget::CurrentColor"),
+    (0x7dc268be, "TypeInfo", "TypeInfo::TaskNode::View::3D::RegisterLightByListContainer"),
+    (0x7dea6a3a, "TypeInfo", "TypeInfo::Object::3D::Placeable::Terrain::Static"),
+    (0x7e5a9913, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::PhysicallyBasedAnisotropicBoth"),
+    (0x7ea0ced0, "TypeInfo", "TypeInfo::Object::3D::Placeable::Collision::Line::SoundEmitterLine"),
+    (0x7eda1fc0, "TypeInfo", "TypeInfo::Object::3D::Displayset::LODModel"),
+    (0x7f85bc88, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::TRFRShadowmap"),
+    (0x7fba9681, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::TessellatedHairShadowmapGeneralLSP"),
+    (0x80d359ae, "TypeInfo", "TypeInfo::TaskNode::View::3D::EndRegisterPrimitive"),
+    (0x815f5484, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::TRBRShadowmap"),
+    (0x81dff790, "TypeInfo", "TypeInfo::Object::Sequence::SequenceEngineCallback"),
+    (0x81eb4d59, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::StaticIllumination"),
+    (0x821ee447, "TypeInfo", "TypeInfo::Object::Param::Frame"),
+    (0x8270ea73, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::PhysicallyBasedTRLCMetal"),
+    (0x82c549b9, "TypeInfo", "TypeInfo::Object::3D::Displayset::ScreenLayoutFreeShape"),
+    (0x82cb14e6, "TypeInfo", "TypeInfo::Object::3D::Placeable::GVDCollisionScene"),
+    (0x830694c8, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::NearZ"),
+    (0x8361f814, "TypeInfo", "TypeInfo::Object::Render::OffScreenRenderingParams"),
+    (0x83f5b40d, "TypeInfo", "TypeInfo::Object::3D::StaticNature"),
+    (0x83fc8036, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::MergeRLR"),
+    (0x840460b9, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::Standard"),
+    (0x842ccdc7, "TypeInfo", "TypeInfo::RenderNode::3D::Fluid"),
+    (0x8432fe6b, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::TessellatedHairShadowmapLSP"),
+    (0x849aa5be, "TypeInfo", "TypeInfo::Object::3D::Placeable::MotorLineList"),
+    (0x84e77ae2, "TypeInfo", "TypeInfo::RenderNode::3D::MergeRLRPass"),
+    (0x8687085e, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::DeferredShading2Pass"),
+    (0x86a8b2fd, "TypeInfo", "TypeInfo::RenderNode::RenderBlock::EndCheckSkip"),
+    (0x8764d2c9, "TypeInfo", "TypeInfo::RenderNode::3D::ClusterLasmapCulling"),
+    (0x896435f1, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::MakeSkyAmbient"),
+    (0x8a69ef4e, "TypeInfo", "TypeInfo::Object::Param::RadialBlurParams"),
+    (0x8ae287a8, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::TRCPShadowmapGeneralLSP"),
+    (0x8b983e31, "TypeInfo", "TypeInfo::TaskNode::View::3D::RegisterScenePlaceableByListContainer"),
+    (0x8beeebbb, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::PhysicallyBasedAnisotropicMetal"),
+    (0x8c60743d, "TypeInfo", "TypeInfo::RenderNode::3D::ClusterLightCulling"),
+    (0x8d034d41, "TypeInfo", "TypeInfo::Object::MotorScreenLayoutCommonSetting"),
+    (0x8db962d2, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::ApplySSAO"),
+    (0x8dbadc47, "TypeInfo", "TypeInfo::Object::3D::Displayset::CylinderPrimitive"),
+    (0x8eacaefc, "TypeInfo", "TypeInfo::Object::3D::Placeable::Collision::Capsule"),
+    (0x8f223821, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::RealtimeLocalReflection"),
+    (0x8f601ab1, "TypeInfo", "TypeInfo::Object::3D::Placeable::GeometryScene"),
+    (0x90a2b799, "TypeInfo", "TypeInfo::TaskNode::Util::UpdatePlaceable"),
+    (0x90bef560, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::ScreenLayout"),
+    (0x916eaaa2, "TypeInfo", "TypeInfo::Object::3D::Displayset::Grass"),
+    (0x918c505c, "TypeInfo", "TypeInfo::Object::MotorEventSetting"),
+    (0x927d5e8b, "TypeInfo", "TypeInfo::Object::3D::Placeable::Terrain::RefStatic"),
+    (0x9398414a, "TypeInfo", "TypeInfo::Object::3D::Placeable::Collision::Box::SoundStreamEmitterBox"),
+    (0x93b32a00, "TypeInfo", "TypeInfo::Object::Sound::AmbientSetting"),
+    (0x93b7d58a, "TypeInfo", "TypeInfo::Object::3D::Placeable::Collision::Prism::SoundStreamEmitterPrism"),
+    (0x93ffbe0f, "TypeInfo", "TypeInfo::RenderNode::3D::DeferredIndirectPass"),
+    (0x94ec3f8e, "TypeInfo", "TypeInfo::Object::3D::Placeable::Collision::Box::SoundPortalBox"),
+    (0x952cc20d, "TypeInfo", "TypeInfo::RenderNode::PostEffect::Fade"),
+    (0x967526e8, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::MergeRLR2"),
+    (0x97299f9c, "TypeInfo", "TypeInfo::RenderNode::3D::CopyColorRenderTarget"),
+    (0x974fdbab, "TypeInfo", "TypeInfo::Object::3D::Placeable::EditTerrainSplineTool"),
+    (0x97559857, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::PhysicallyBasedTRLCStandard"),
+    (0x9773131b, "TypeInfo", "TypeInfo::Object::3D::Fog::ThreePoints"),
+    (0x97af26fd, "TypeInfo", "TypeInfo::Object::3D::View"),
+    (0x9900f674, "TypeInfo", "TypeInfo::Object::3D::LayeredBRDFLayers"),
+    (0x99e1a4f0, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::Noise"),
+    (0x9a44face, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::Tree2Shadowmap"),
+    (0x9ba40b40, "TypeInfo", "TypeInfo::RenderNode::3D::GrassVertexDisplacement"),
+    (0x9c1ced03, "TypeInfo", "TypeInfo::Object::Render::FontParameter"),
+    (0x9c41ae45, "TypeInfo", "TypeInfo::Object::3D::Placeable::OceanRipple"),
+    (0x9cfb4e6a, "TypeInfo", "TypeInfo::Object::3D::Placeable::Collision::Box::SoundEmitterBox"),
+    (0x9d2baa27, "TypeInfo", "TypeInfo::Object::Sound::ReverbPreset"),
+    (0x9d95a247, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::TRCPShadowmap"),
+    (0x9dd803ea, "TypeInfo", "TypeInfo::Object::3D::Placeable::RainDropBoundary"),
+    (0x9e20a442, "TypeInfo", "TypeInfo::Object::3D::Placeable::SpriteWindow"),
+    (0x9e699557, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::PhysicallyBasedAnisotropicStandard"),
+    (0x9f649b3f, "TypeInfo", "TypeInfo::RenderNode::3D::EdgePass"),
+    (0xa033e931, "TypeInfo", "TypeInfo::RenderNode::3D::SSSS"),
+    (0xa066f0c4, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::TwinkleEyeTranslucence"),
+    (0xa0df749b, "TypeInfo", "TypeInfo::Object::3D::Placeable::Collision::Box::SoundOccluderBox"),
+    (0xa148f282, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::TessellatedHairShadowmap"),
+    (0xa14905a3, "TypeInfo", "TypeInfo::Object::DopeSheet::EnqProperties"),
+    (0xa1a0d66a, "TypeInfo", "TypeInfo::Object::3D::Placeable::Collision::Prism::SoundSwitchPrism"),
+    (0xa1a84f2c, "TypeInfo", "TypeInfo::Object::MotorScreenLayoutSetting"),
+    (0xa1b9fc1f, "TypeInfo", "TypeInfo::Object::Physics::SceneParam"),
+    (0xa1e036de, "TypeInfo", "TypeInfo::Object::3D::ModelPropertyAnimation"),
+    (0xa3a332e5, "TypeInfo", "TypeInfo::Object::Sequence::SequenceVariable"),
+    (0xa3d70551, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::PhysicallyBasedTRCPBoth"),
+    (0xa41fdb1c, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::PicturePane"),
+    (0xa51e3d31, "TypeInfo", "TypeInfo::Object::Render::Texture::StaticForSLE"),
+    (0xa5f18364, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::AnisotropicStandard"),
+    (0xa7faf689, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::PhysicallyBased2TRFR"),
+    (0xa82d6d40, "TypeInfo", "TypeInfo::RenderNode::3D::GBufferEdit"),
+    (0xa880e514, "TypeInfo", "TypeInfo::Object::DopeSheet::RenderParts::PartsSet"),
+    (0xa8f14404, "TypeInfo", "TypeInfo::Object::Render::Material"),
+    (0xa99dfa37, "TypeInfo", "TypeInfo::Object::DopeSheet::Effect"),
+    (0xa9a72ffa, "TypeInfo", "TypeInfo::Object::3D::ModelProperty"),
+    (0xab5b4109, "TypeInfo", "TypeInfo::TaskNode::Util::FrameSync"),
+    (0xab9c4087, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::PhysicallyBased2Tree2"),
+    (0xaba56f34, "TypeInfo", "TypeInfo::Object::3D::SkyDome"),
+    (0xac078655, "TypeInfo", "TypeInfo::RenderNode::3D::RealtimeLocalReflectionPass"),
+    (0xacd8ad37, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::FE4LandConstantColor"),
+    (0xadc35ba3, "TypeInfo", "TypeInfo::Object::DopeSheet::Camera"),
+    (0xadc66468, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::RadialBlurPane"),
+    (0xae91ca0f, "TypeInfo", "TypeInfo::TaskNode::View::3D::BeginRegisterLight"),
+    (0xb06b232d, "TypeInfo", "TypeInfo::RenderNode::3D::ReflectionPass"),
+    (0xb10e86e9, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::PhysicallyBased2Grass"),
+    (0xb13c6bc5, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::SVAO"),
+    (0xb143860b, "TypeInfo", "TypeInfo::Object::3D::Placeable::Nature::Static::Updater"),
+    (0xb2d30063, "TypeInfo", "TypeInfo::RenderNode::PostEffect::Apply"),
+    (0xb51bbd26, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::PhysicallyBasedBoth"),
+    (0xb527c14a, "TypeInfo", "TypeInfo::Object::3D::Displayset::LineList"),
+    (0xb5ea88f0, "TypeInfo", "TypeInfo::Object::3D::ViewParam"),
+    (0xb835376d, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::PhysicallyBasedBothVtxa"),
+    (0xb9eea163, "TypeInfo", "TypeInfo::Object::3D::Displayset::BlendMap"),
+    (0xba7900f0, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::PhysicallyBasedTRFRBoth"),
+    (0xbb29546e, "TypeInfo", "TypeInfo::Object::Container::Stack"),
+    (0xbb5cc587, "TypeInfo", "TypeInfo::Object::ResourceKeeper"),
+    (0xbbb90d65, "TypeInfo", "TypeInfo::Object::3D::Placeable::SpriteText"),
+    (0xbbc7bf3d, "TypeInfo", "TypeInfo::Object::Render::Texture::Video"),
+    (0xbc8af7c9, "TypeInfo", "TypeInfo::Object::Physics::Action::EntityTree"),
+    (0xbf58eea6, "TypeInfo", "TypeInfo::Object::3D::Placeable::Sound::SoundEffect"),
+    (0xbf6b52c7, "TypeInfo", "TypeInfo::Resource::System::NameDatabaseFile"),
+    (0xbff00946, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::NDF"),
+    (0xc0f4f174, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::PhysicallyBasedTRBRBoth"),
+    (0xc10c91f9, "TypeInfo", "TypeInfo::RenderNode::3D::RenderGraph"),
+    (0xc1242145, "TypeInfo", "TypeInfo::Object::Render::State::Material"),
+    (0xc18a1958, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::Ripple2"),
+    (0xc1b42047, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::GrassVertexDisplacement"),
+    (0xc22ff2b6, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::SSSS"),
+    (0xc27bf870, "TypeInfo", "TypeInfo::Object::Animation::Data::FCurve"),
+    (0xc30d3135, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::Effect2Outline"),
+    (0xc3a7fc79, "TypeInfo", "TypeInfo::RenderNode::3D::OldEffect"),
+    (0xc40552a0, "TypeInfo", "TypeInfo::Object::Render::FontsetWriterParameter"),
+    (0xc41f73b1, "TypeInfo", "TypeInfo::RenderNode::3D::PrepareThinSurfaceBSDF"),
+    (0xc4b9b28d, "TypeInfo", "TypeInfo::Object::3D::Placeable::Model::WorldPQ"),
+    (0xc6394f4c, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::Grass"),
+    (0xc640006b, "TypeInfo", "TypeInfo::Object::Video::Streamset::MotorFilePathBased"),
+    (0xc6e7b9e8, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::EdgePass"),
+    (0xc72f2cbe, "TypeInfo", "TypeInfo::Object::DopeSheet::SpotWind"),
+    (0xc78fe503, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::PhysicallyBased2ScreenMap"),
+    (0xc870838f, "TypeInfo", "TypeInfo::Object::3D::Placeable::ColorBox"),
+    (0xc8a6c3c1, "TypeInfo", "TypeInfo::RenderNode::3D::Ripple"),
+    (0xc8dfca2b, "TypeInfo", "TypeInfo::Object::Sound::GlobalConfiguration"),
+    (0xcad70bc8, "TypeInfo", "TypeInfo::RenderNode::3D::SkyAmbient"),
+    (0xcafc88f2, "TypeInfo", "TypeInfo::Object::DopeSheet::ApplyAnimation"),
+    (0xcb173e65, "TypeInfo", "TypeInfo::Object::3D::Fog::Distance"),
+    (0xcca31f3a, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::ReflectionPass"),
+    (0xcd5a3bc4, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::SnowDrop"),
+    (0xcd713e46, "TypeInfo", "TypeInfo::RenderNode::RenderTarget::ClearScene"),
+    (0xce6ca57e, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::WritefShadowPass"),
+    (0xcf1ff0c9, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::ShadowmapLSP"),
+    (0xcf8972e8, "TypeInfo", "TypeInfo::Object::3D::VisibilitySet"),
+    (0xd0adb596, "TypeInfo", "TypeInfo::Object::3D::Placeable::NavigationArea"),
+    (0xd186ceeb, "TypeInfo", "TypeInfo::Object::MotorCharacterSetting"),
+    (0xd2f1778a, "TypeInfo", "TypeInfo::Object::3D::Placeable::Collision::Point::SoundEmitterPoint"),
+    (0xd40b3c8f, "TypeInfo", "TypeInfo::Object::3D::Displayset::Model"),
+    (0xd4430275, "TypeInfo", "TypeInfo::TaskNode::Util::EvaluateScript"),
+    (0xd7441e4e, "TypeInfo", "TypeInfo::Object::Render::Texture::IESLut"),
+    (0xd8004a77, "TypeInfo", "TypeInfo::Object::DopeSheet::Sound"),
+    (0xd8272294, "TypeInfo", "TypeInfo::Object::Render::Texture::RenderTarget::Array"),
+    (0xd960609a, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::EdgePass2"),
+    (0xdac911d7, "TypeInfo", "TypeInfo::Object::MotorCommonSetting"),
+    (0xdbc6fe01, "TypeInfo", "TypeInfo::Object::3D::RayTrace::AO"),
+    (0xddd0e775, "TypeInfo", "TypeInfo::Object::3D::OccluderUnit"),
+    (0xded9ec46, "TypeInfo", "TypeInfo::Object::3D::Placeable::StatisticalRiver"),
+    (0xdfcdf56b, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::Resolve4KGeometry"),
+    (0xe01606f4, "TypeInfo", "TypeInfo::Object::3D::Placeable::Light::Parallel"),
+    (0xe0d88e3f, "TypeInfo", "TypeInfo::Object::Script::Code"),
+    (0xe1d40600, "TypeInfo", "TypeInfo::Object::MotorStageSetting"),
+    (0xe1ec7282, "TypeInfo", "TypeInfo::Object::3D::Placeable::StreamWaterRiver"),
+    (0xe1fe052e, "TypeInfo", "TypeInfo::RenderNode::3D::MergeRLR2Pass"),
+    (0xe25493e2, "TypeInfo", "TypeInfo::RenderNode::3D::OutlineAdditionPass"),
+    (0xe25e1cd6, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::Tree2ShadowmapGeneral"),
+    (0xe2715252, "TypeInfo", "TypeInfo::Object::3D::Placeable::Sprite"),
+    (0xe283dc1c, "TypeInfo", "TypeInfo::Object::Sequence::SequenceManager"),
+    (0xe28519b5, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::TRLCShadowmapGeneral"),
+    (0xe2c0442a, "TypeInfo", "TypeInfo::RenderNode::RenderTarget::BeginScene"),
+    (0xe31194c9, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::EffDistPane"),
+    (0xe3c72c83, "TypeInfo", "TypeInfo::Object::Sprite::TextData"),
+    (0xe43743b2, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::WriteDepth"),
+    (0xe494e874, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::TRLCShadowmapLSP"),
+    (0xe4cb21fe, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::SDFOcclusion"),
+    (0xe681df21, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::TRLC"),
+    (0xe69aad81, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::PhysicallyBased2DeferredDecal"),
+    (0xe736b039, "TypeInfo", "TypeInfo::Object::3D::Placeable::Effect::WorldPQ"),
+    (0xe76d8639, "TypeInfo", "TypeInfo::RenderNode::3D::SnowDeform"),
+    (0xe8afc192, "TypeInfo", "TypeInfo::Object::3D::Placeable::Waypoint::Vertex"),
+    (0xe8c11c6c, "TypeInfo", "TypeInfo::Object::Physics::Scene"),
+    (0xe93eb6c7, "TypeInfo", "TypeInfo::Object::3D::Placeable::ColorSphere"),
+    (0xe947ec4a, "TypeInfo", "TypeInfo::TaskNode::Behavior::Update"),
+    (0xe97f942a, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::ClusterLightCulling"),
+    (0xea12cd84, "TypeInfo", "TypeInfo::RenderNode::3D::ApplySSAOPass"),
+    (0xea5d7e48, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::PhysicallyBased2TRCP"),
+    (0xea69fa13, "TypeInfo", "TypeInfo::Object::Render::State::PostEffect3Shader"),
+    (0xeba1dcf4, "TypeInfo", "TypeInfo::RenderNode::3D::RainDrop"),
+    (0xebfe38e8, "TypeInfo", "TypeInfo::RenderNode::3D::SignedDistanceField"),
+    (0xec5647e9, "TypeInfo", "TypeInfo::Object::3D::Displayset::Effect"),
+    (0xec656a13, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::RainDrop"),
+    (0xedda84f3, "TypeInfo", "TypeInfo::Object::Param::Parallel"),
+    (0xef3d849d, "TypeInfo", "TypeInfo::Object::3D::View::CommonViewSetting"),
+    (0xefd13e9f, "TypeInfo", "TypeInfo::Object::3D::Placeable::Wind::Point"),
+    (0xf041ce62, "TypeInfo", "TypeInfo::Object::3D::Placeable::Location"),
+    (0xf048026c, "TypeInfo", "TypeInfo::TaskNode::View::3D::RegisterWindByOctreeManager"),
+    (0xf103db00, "TypeInfo", "TypeInfo::RenderNode::3D::ClusteredDeferredShading2Pass"),
+    (0xf1fbffba, "TypeInfo", "TypeInfo::Object::Empty"),
+    (0xf30ac4d6, "TypeInfo", "TypeInfo::Object::3D::Placeable::Collision::Box::SoundSwitchBox"),
+    (0xf3bd0179, "TypeInfo", "TypeInfo::Object::Render::Texture::RenderTarget::DefaultDepth"),
+    (0xf3ddb690, "TypeInfo", "TypeInfo::Object::3D::Placeable::StaticScreenLayoutListView"),
+    (0xf4adc3e6, "TypeInfo", "TypeInfo::Object::Render::Texture::RenderTarget::Cube"),
+    (0xf4c31252, "TypeInfo", "TypeInfo::Object::DopeSheet::RenderParts"),
+    (0xf4ff90e0, "TypeInfo", "TypeInfo::Object::DopeSheet::ExForce"),
+    (0xf58923b8, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::PhysicallyBasedTree2Metal"),
+    (0xf6151a12, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::TRLCShadowmapCube"),
+    (0xf6f3afbd, "TypeInfo", "TypeInfo::Object::3D::GBufferMaterialIDTable"),
+    (0xf71448b6, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::PhysicallyBased2Fur"),
+    (0xf7c0d0f0, "TypeInfo", "TypeInfo::Object::Sprite::TexPatternAnimData"),
+    (0xf977a309, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::PBRayTrace"),
+    (0xfa05a5ea, "TypeInfo", "TypeInfo::RenderNode::3D::LightPass"),
+    (0xfb79ef73, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::ShadowmapGeneralLSP"),
+    (0xfbbf86f2, "TypeInfo", "TypeInfo::Object::AI::BehaviorTree::Manager"),
+    (0xfbd368b4, "TypeInfo", "TypeInfo::RenderNode::3D::ClusteredDeferredShadingPass"),
+    (0xfc64dcb6, "TypeInfo", "TypeInfo::RenderNode::3D::View"),
+    (0xfd1176c6, "TypeInfo", "TypeInfo::RenderNode::3D::PrepareStaticNatureReflaction"),
+    (0xfd823542, "TypeInfo", "TypeInfo::Object::DopeSheet::PointLight"),
+    (0xfdf00e20, "TypeInfo", "TypeInfo::Object::3D::LocationManager"),
+    (0xfe476315, "TypeInfo", "TypeInfo::Object::Render::State::3D::Shader::ESM"),
+    (0xff7dbfd4, "TypeInfo", "TypeInfo::Object::Render::Texture::Static"),
+];
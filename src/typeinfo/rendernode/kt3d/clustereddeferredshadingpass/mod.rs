@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(4224936116);
\ No newline at end of file
+/// KTID of `TypeInfo::RenderNode::3D::ClusteredDeferredShadingPass`
+pub const ID: KTID = KTID(4224936116);
+pub const NAME: &str = "TypeInfo::RenderNode::3D::ClusteredDeferredShadingPass";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
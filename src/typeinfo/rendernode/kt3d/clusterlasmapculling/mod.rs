@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(2271531721);
\ No newline at end of file
+/// KTID of `TypeInfo::RenderNode::3D::ClusterLasmapCulling`
+pub const ID: KTID = KTID(2271531721);
+pub const NAME: &str = "TypeInfo::RenderNode::3D::ClusterLasmapCulling";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(3791521070);
\ No newline at end of file
+/// KTID of `TypeInfo::RenderNode::3D::MergeRLR2Pass`
+pub const ID: KTID = KTID(3791521070);
+pub const NAME: &str = "TypeInfo::RenderNode::3D::MergeRLR2Pass";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,16 @@
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::RenderNode::3D`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(3229105457);
+pub const NAME: &str = "TypeInfo::RenderNode::3D";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod applyssaopass;
 pub mod clusterdecalculling;
 pub mod clustereddeferredshading2pass;
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(386529751);
\ No newline at end of file
+/// KTID of `TypeInfo::RenderNode::3D::NatureSky`
+pub const ID: KTID = KTID(386529751);
+pub const NAME: &str = "TypeInfo::RenderNode::3D::NatureSky";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(3282566265);
\ No newline at end of file
+/// KTID of `TypeInfo::RenderNode::3D::OldEffect`
+pub const ID: KTID = KTID(3282566265);
+pub const NAME: &str = "TypeInfo::RenderNode::3D::OldEffect";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
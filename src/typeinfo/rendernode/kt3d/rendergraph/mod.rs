@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(3238826489);
\ No newline at end of file
+/// KTID of `TypeInfo::RenderNode::3D::RenderGraph`
+pub const ID: KTID = KTID(3238826489);
+pub const NAME: &str = "TypeInfo::RenderNode::3D::RenderGraph";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(3959306472);
\ No newline at end of file
+/// KTID of `TypeInfo::RenderNode::3D::SignedDistanceField`
+pub const ID: KTID = KTID(3959306472);
+pub const NAME: &str = "TypeInfo::RenderNode::3D::SignedDistanceField";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
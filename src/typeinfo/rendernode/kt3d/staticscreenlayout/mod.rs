@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(1213662229);
\ No newline at end of file
+/// KTID of `TypeInfo::RenderNode::3D::StaticScreenLayout`
+pub const ID: KTID = KTID(1213662229);
+pub const NAME: &str = "TypeInfo::RenderNode::3D::StaticScreenLayout";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
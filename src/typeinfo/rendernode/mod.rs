@@ -1,3 +1,16 @@
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::RenderNode`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(634081728);
+pub const NAME: &str = "TypeInfo::RenderNode";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod kt3d;
 pub mod posteffect;
 pub mod renderblock;
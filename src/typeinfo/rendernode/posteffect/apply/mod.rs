@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(3000172643);
\ No newline at end of file
+/// KTID of `TypeInfo::RenderNode::PostEffect::Apply`
+pub const ID: KTID = KTID(3000172643);
+pub const NAME: &str = "TypeInfo::RenderNode::PostEffect::Apply";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
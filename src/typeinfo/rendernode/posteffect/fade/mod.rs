@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(2502738445);
\ No newline at end of file
+/// KTID of `TypeInfo::RenderNode::PostEffect::Fade`
+pub const ID: KTID = KTID(2502738445);
+pub const NAME: &str = "TypeInfo::RenderNode::PostEffect::Fade";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
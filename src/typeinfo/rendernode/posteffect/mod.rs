@@ -1,2 +1,15 @@
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::RenderNode::PostEffect`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(3780614321);
+pub const NAME: &str = "TypeInfo::RenderNode::PostEffect";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod apply;
 pub mod fade;
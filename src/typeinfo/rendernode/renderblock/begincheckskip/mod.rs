@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(1552650315);
\ No newline at end of file
+/// KTID of `TypeInfo::RenderNode::RenderBlock::BeginCheckSkip`
+pub const ID: KTID = KTID(1552650315);
+pub const NAME: &str = "TypeInfo::RenderNode::RenderBlock::BeginCheckSkip";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
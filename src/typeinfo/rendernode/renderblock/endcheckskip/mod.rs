@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(2259202813);
\ No newline at end of file
+/// KTID of `TypeInfo::RenderNode::RenderBlock::EndCheckSkip`
+pub const ID: KTID = KTID(2259202813);
+pub const NAME: &str = "TypeInfo::RenderNode::RenderBlock::EndCheckSkip";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
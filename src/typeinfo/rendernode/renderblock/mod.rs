@@ -1,2 +1,15 @@
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::RenderNode::RenderBlock`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(676560233);
+pub const NAME: &str = "TypeInfo::RenderNode::RenderBlock";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod begincheckskip;
 pub mod endcheckskip;
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(3804251178);
\ No newline at end of file
+/// KTID of `TypeInfo::RenderNode::RenderTarget::BeginScene`
+pub const ID: KTID = KTID(3804251178);
+pub const NAME: &str = "TypeInfo::RenderNode::RenderTarget::BeginScene";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(3446750790);
\ No newline at end of file
+/// KTID of `TypeInfo::RenderNode::RenderTarget::ClearScene`
+pub const ID: KTID = KTID(3446750790);
+pub const NAME: &str = "TypeInfo::RenderNode::RenderTarget::ClearScene";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
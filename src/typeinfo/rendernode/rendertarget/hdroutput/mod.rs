@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(680618704);
\ No newline at end of file
+/// KTID of `TypeInfo::RenderNode::RenderTarget::HdrOutput`
+pub const ID: KTID = KTID(680618704);
+pub const NAME: &str = "TypeInfo::RenderNode::RenderTarget::HdrOutput";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,16 @@
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::RenderNode::RenderTarget`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(165604903);
+pub const NAME: &str = "TypeInfo::RenderNode::RenderTarget";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod beginscene;
 pub mod clearscene;
 pub mod endscene;
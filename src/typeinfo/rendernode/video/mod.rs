@@ -1 +1,14 @@
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::RenderNode::Video`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(271330629);
+pub const NAME: &str = "TypeInfo::RenderNode::Video";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod player;
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(2062224228);
\ No newline at end of file
+/// KTID of `TypeInfo::RenderNode::Video::Player`
+pub const ID: KTID = KTID(2062224228);
+pub const NAME: &str = "TypeInfo::RenderNode::Video::Player";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1 +1,14 @@
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::Resource`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(678089366);
+pub const NAME: &str = "TypeInfo::Resource";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod system;
@@ -1,2 +1,15 @@
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::Resource::System`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(1768027301);
+pub const NAME: &str = "TypeInfo::Resource::System";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod namedatabasefile;
 pub mod objectdatabasefile;
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(3211481799);
\ No newline at end of file
+/// KTID of `TypeInfo::Resource::System::NameDatabaseFile`
+pub const ID: KTID = KTID(3211481799);
+pub const NAME: &str = "TypeInfo::Resource::System::NameDatabaseFile";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
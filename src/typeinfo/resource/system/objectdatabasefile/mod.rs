@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(547791035);
\ No newline at end of file
+/// KTID of `TypeInfo::Resource::System::ObjectDatabaseFile`
+pub const ID: KTID = KTID(547791035);
+pub const NAME: &str = "TypeInfo::Resource::System::ObjectDatabaseFile";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1 +1,14 @@
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::TaskNode::Behavior`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(2023683297);
+pub const NAME: &str = "TypeInfo::TaskNode::Behavior";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod update;
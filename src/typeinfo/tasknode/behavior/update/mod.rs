@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(3913804874);
\ No newline at end of file
+/// KTID of `TypeInfo::TaskNode::Behavior::Update`
+pub const ID: KTID = KTID(3913804874);
+pub const NAME: &str = "TypeInfo::TaskNode::Behavior::Update";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
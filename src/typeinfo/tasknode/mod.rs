@@ -1,3 +1,16 @@
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::TaskNode`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(2343556463);
+pub const NAME: &str = "TypeInfo::TaskNode";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod behavior;
 pub mod physics;
 pub mod system;
@@ -1 +1,14 @@
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::TaskNode::Physics`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(2814679848);
+pub const NAME: &str = "TypeInfo::TaskNode::Physics";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod simulate;
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(262730532);
\ No newline at end of file
+/// KTID of `TypeInfo::TaskNode::Physics::Simulate`
+pub const ID: KTID = KTID(262730532);
+pub const NAME: &str = "TypeInfo::TaskNode::Physics::Simulate";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(1285866097);
\ No newline at end of file
+/// KTID of `TypeInfo::TaskNode::System::Empty`
+pub const ID: KTID = KTID(1285866097);
+pub const NAME: &str = "TypeInfo::TaskNode::System::Empty";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
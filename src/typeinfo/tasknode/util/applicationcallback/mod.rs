@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(1452477468);
\ No newline at end of file
+/// KTID of `TypeInfo::TaskNode::Util::ApplicationCallback`
+pub const ID: KTID = KTID(1452477468);
+pub const NAME: &str = "TypeInfo::TaskNode::Util::ApplicationCallback";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(3561161333);
\ No newline at end of file
+/// KTID of `TypeInfo::TaskNode::Util::EvaluateScript`
+pub const ID: KTID = KTID(3561161333);
+pub const NAME: &str = "TypeInfo::TaskNode::Util::EvaluateScript";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
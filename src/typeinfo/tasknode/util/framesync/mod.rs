@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(2874884361);
\ No newline at end of file
+/// KTID of `TypeInfo::TaskNode::Util::FrameSync`
+pub const ID: KTID = KTID(2874884361);
+pub const NAME: &str = "TypeInfo::TaskNode::Util::FrameSync";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
@@ -1,3 +1,16 @@
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::TaskNode::Util`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(1256621137);
+pub const NAME: &str = "TypeInfo::TaskNode::Util";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod applicationcallback;
 pub mod evaluatescript;
 pub mod framesync;
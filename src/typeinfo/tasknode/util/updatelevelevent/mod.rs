@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(1141350096);
\ No newline at end of file
+/// KTID of `TypeInfo::TaskNode::Util::UpdateLevelEvent`
+pub const ID: KTID = KTID(1141350096);
+pub const NAME: &str = "TypeInfo::TaskNode::Util::UpdateLevelEvent";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
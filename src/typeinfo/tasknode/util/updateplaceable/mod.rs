@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(2426582937);
\ No newline at end of file
+/// KTID of `TypeInfo::TaskNode::Util::UpdatePlaceable`
+pub const ID: KTID = KTID(2426582937);
+pub const NAME: &str = "TypeInfo::TaskNode::Util::UpdatePlaceable";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
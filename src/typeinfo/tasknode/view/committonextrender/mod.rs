@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(808718255);
\ No newline at end of file
+/// KTID of `TypeInfo::TaskNode::View::CommitToNextRender`
+pub const ID: KTID = KTID(808718255);
+pub const NAME: &str = "TypeInfo::TaskNode::View::CommitToNextRender";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
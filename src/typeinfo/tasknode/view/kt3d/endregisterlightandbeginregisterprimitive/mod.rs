@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(43443563);
\ No newline at end of file
+/// KTID of `TypeInfo::TaskNode::View::3D::EndRegisterLightAndBeginRegisterPrimitive`
+pub const ID: KTID = KTID(43443563);
+pub const NAME: &str = "TypeInfo::TaskNode::View::3D::EndRegisterLightAndBeginRegisterPrimitive";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
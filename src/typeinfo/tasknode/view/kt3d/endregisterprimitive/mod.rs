@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(2161334702);
\ No newline at end of file
+/// KTID of `TypeInfo::TaskNode::View::3D::EndRegisterPrimitive`
+pub const ID: KTID = KTID(2161334702);
+pub const NAME: &str = "TypeInfo::TaskNode::View::3D::EndRegisterPrimitive";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
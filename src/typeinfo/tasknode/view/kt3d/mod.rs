@@ -1,3 +1,16 @@
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::TaskNode::View::3D`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(3117385637);
+pub const NAME: &str = "TypeInfo::TaskNode::View::3D";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod beginregisterlight;
 pub mod endregisterlightandbeginregisterprimitive;
 pub mod endregisterprimitive;
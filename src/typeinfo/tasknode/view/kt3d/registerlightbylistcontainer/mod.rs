@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(2109892798);
\ No newline at end of file
+/// KTID of `TypeInfo::TaskNode::View::3D::RegisterLightByListContainer`
+pub const ID: KTID = KTID(2109892798);
+pub const NAME: &str = "TypeInfo::TaskNode::View::3D::RegisterLightByListContainer";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
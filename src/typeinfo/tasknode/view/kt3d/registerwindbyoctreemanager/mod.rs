@@ -1,3 +1,12 @@
 use crate::ktid::KTID;
 
-pub const ID: KTID = KTID(4031251052);
\ No newline at end of file
+/// KTID of `TypeInfo::TaskNode::View::3D::RegisterWindByOctreeManager`
+pub const ID: KTID = KTID(4031251052);
+pub const NAME: &str = "TypeInfo::TaskNode::View::3D::RegisterWindByOctreeManager";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
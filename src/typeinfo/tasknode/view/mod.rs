@@ -1,2 +1,15 @@
-pub mod kt3d;
+use crate::ktid::KTID;
+
+/// KTID of `TypeInfo::TaskNode::View`, the parent of the types below. It isn't part of typeinfos.csv
+pub const ID: KTID = KTID(1599585460);
+pub const NAME: &str = "TypeInfo::TaskNode::View";
+
+pub struct Type;
+
+impl crate::typeinfo::TypeInfo for Type {
+    const ID: KTID = ID;
+    const NAME: &'static str = NAME;
+}
+
 pub mod committonextrender;
+pub mod kt3d;
@@ -0,0 +1,217 @@
+use std::{collections::HashSet, fmt, io::{Seek, SeekFrom}, path::{Path, PathBuf}};
+
+use binread::{io::Cursor, BinReaderExt};
+
+use crate::error::Result;
+use crate::idrk::{extra_info_size, IdrkHeader, IDRK_HEADER_SIZE};
+use crate::ktid::KTID;
+use crate::rdb::{Rdb, RdbEntry, RdbHeader, RdbLocation, RDB_HEADER_SIZE};
+
+/// Structural problem found in a RDB
+#[derive(Debug, Clone, PartialEq)]
+pub enum Problem {
+    /// The header is too small to hold the path, or isn't aligned
+    HeaderSize { header_size: u32, minimum: u32 },
+    /// Fewer entries than announced could be parsed
+    MissingEntries { file_count: u32, parsed: u32, reason: String },
+    /// Data follows the last entry, the file count might be too low
+    TrailingData { file_count: u32, size: u64 },
+    /// The entry size doesn't match the fixed fields, extra info and name
+    EntrySize { ktid: KTID, entry_size: u32, expected: u32 },
+    /// The extra info doesn't have the size expected for the entry type
+    ExtraInfoSize { ktid: KTID, entry_type: u32, size: u32, expected: u32 },
+    UnknownEntryType { ktid: KTID, entry_type: u32 },
+    /// The `@size` marker of the name doesn't match the file size
+    SizeMarker { ktid: KTID, marker: String, file_size: u64 },
+    DuplicateKtid(KTID),
+    MissingFile { ktid: KTID, path: PathBuf },
+    /// The IDRK blob of the entry is unreadable or doesn't describe the entry
+    BadIdrk { ktid: KTID, path: PathBuf, reason: String },
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Problem::HeaderSize { header_size, minimum } => write!(f, "Header size 0x{:x} is invalid (at least 0x{:x}, aligned on 4)", header_size, minimum),
+            Problem::MissingEntries { file_count, parsed, reason } => write!(f, "File count is {} but only {} entries could be parsed: {}", file_count, parsed, reason),
+            Problem::TrailingData { file_count, size } => write!(f, "0x{:x} bytes follow the last entry, the file count ({}) might be too low", size, file_count),
+            Problem::EntrySize { ktid, entry_size, expected } => write!(f, "0x{:08x}: entry size is 0x{:x}, expected 0x{:x}", ktid, entry_size, expected),
+            Problem::ExtraInfoSize { ktid, entry_type, size, expected } => write!(f, "0x{:08x}: extra info is 0x{:x} bytes long, expected 0x{:x} for entry type {}", ktid, size, expected, entry_type),
+            Problem::UnknownEntryType { ktid, entry_type } => write!(f, "0x{:08x}: unknown entry type {}", ktid, entry_type),
+            Problem::SizeMarker { ktid, marker, file_size } => write!(f, "0x{:08x}: size marker '@{}' doesn't match the file size 0x{:x}", ktid, marker, file_size),
+            Problem::DuplicateKtid(ktid) => write!(f, "0x{:08x}: KTID is used by more than one entry", ktid),
+            Problem::MissingFile { ktid, path } => write!(f, "0x{:08x}: '{}' is missing", ktid, path.display()),
+            Problem::BadIdrk { ktid, path, reason } => write!(f, "0x{:08x}: IDRK blob in '{}' is invalid: {}", ktid, path.display(), reason),
+        }
+    }
+}
+
+/// Check the structure of a RDB file and the files its entries point to
+pub fn verify_file<P: AsRef<Path>>(path: P) -> Result<Vec<Problem>> {
+    let path = path.as_ref();
+    let bytes = std::fs::read(path)?;
+    let rdb_dir = path.parent().unwrap_or_else(|| Path::new(""));
+
+    verify_bytes(&bytes, rdb_dir)
+}
+
+/// Check the structure of a RDB. `rdb_dir` is the directory containing the RDB, used to find the files of the entries.
+/// Only an unreadable header is an error, every other problem is reported.
+pub fn verify_bytes(bytes: &[u8], rdb_dir: &Path) -> Result<Vec<Problem>> {
+    let mut problems = vec![];
+    let mut reader = Cursor::new(bytes);
+
+    let header: RdbHeader = reader.read_le()?;
+
+    let minimum = RDB_HEADER_SIZE + header.path.len() as u32 + 1;
+    if header.header_size < minimum || header.header_size % 4 != 0 {
+        problems.push(Problem::HeaderSize { header_size: header.header_size, minimum });
+    }
+
+    reader.seek(SeekFrom::Start(header.header_size as u64))?;
+
+    let mut entries = vec![];
+
+    for parsed in 0..header.file_count {
+        match reader.read_le::<RdbEntry>() {
+            Ok(entry) => entries.push(entry),
+            Err(err) => {
+                problems.push(Problem::MissingEntries { file_count: header.file_count, parsed, reason: crate::Error::from(err).to_string() });
+                break;
+            },
+        }
+    }
+
+    let end = reader.position().min(bytes.len() as u64);
+    if entries.len() as u32 == header.file_count && end < bytes.len() as u64 {
+        problems.push(Problem::TrailingData { file_count: header.file_count, size: bytes.len() as u64 - end });
+    }
+
+    let rdb = Rdb { header, entries };
+    problems.extend(verify_entries(&rdb, rdb_dir));
+
+    Ok(problems)
+}
+
+/// Check the entries of a parsed RDB
+pub fn verify_entries(rdb: &Rdb, rdb_dir: &Path) -> Vec<Problem> {
+    let mut problems = vec![];
+    let mut seen = HashSet::new();
+
+    for entry in &rdb.entries {
+        let ktid = entry.file_ktid;
+
+        if !seen.insert(ktid) {
+            problems.push(Problem::DuplicateKtid(ktid));
+        }
+
+        let expected = IDRK_HEADER_SIZE + entry.unk_content.len() as u32 + entry.string_size;
+        if entry.entry_size != expected {
+            problems.push(Problem::EntrySize { ktid, entry_size: entry.entry_size, expected });
+        }
+
+        match extra_info_size(entry.entry_type) {
+            Some(size) if size != entry.unk_content.len() as u32 => problems.push(Problem::ExtraInfoSize { ktid, entry_type: entry.entry_type, size: entry.unk_content.len() as u32, expected: size }),
+            Some(_) => {},
+            None => problems.push(Problem::UnknownEntryType { ktid, entry_type: entry.entry_type }),
+        }
+
+        if let Some(marker) = entry.get_size_marker() {
+            if u64::from_str_radix(marker.trim_start_matches("0x"), 16).ok() != Some(entry.file_size) {
+                problems.push(Problem::SizeMarker { ktid, marker: marker.to_string(), file_size: entry.file_size });
+            }
+        }
+
+        problems.extend(verify_location(entry, rdb_dir));
+    }
+
+    problems
+}
+
+fn verify_location(entry: &RdbEntry, rdb_dir: &Path) -> Option<Problem> {
+    let ktid = entry.file_ktid;
+
+    let (path, offset) = match entry.get_location()? {
+        RdbLocation::External(path) => (rdb_dir.join(path), 0),
+        RdbLocation::Internal { fdata, offset } => (rdb_dir.join(fdata), offset),
+    };
+
+    if !path.exists() {
+        return Some(Problem::MissingFile { ktid, path });
+    }
+
+    let bad_idrk = |reason: String| Some(Problem::BadIdrk { ktid, path: path.clone(), reason });
+
+    let header: IdrkHeader = match std::fs::File::open(&path).map_err(crate::Error::from).and_then(|mut file| {
+        file.seek(SeekFrom::Start(offset))?;
+        Ok(std::io::BufReader::new(file).read_le()?)
+    }) {
+        Ok(header) => header,
+        Err(err) => return bad_idrk(err.to_string()),
+    };
+
+    if header.file_ktid != ktid {
+        return bad_idrk(format!("KTID is 0x{:08x}", header.file_ktid));
+    }
+
+    if header.file_size != entry.file_size {
+        return bad_idrk(format!("file size is 0x{:x}, expected 0x{:x}", header.file_size, entry.file_size));
+    }
+
+    if header.flags.storage_mode() != entry.storage_mode() {
+        return bad_idrk(format!("storage mode is {}, expected {}", header.flags.storage_mode(), entry.storage_mode()));
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::idrk::Idrk;
+    use crate::rdb::{testing::*, StorageMode};
+
+    #[test]
+    fn valid_rdb() {
+        let dir = tempfile::tempdir().unwrap();
+        let bytes = synthetic_rdb("data", &[synthetic_entry(1, 0, "x@1234", 1 << 16)]);
+
+        let mut rdb = Rdb::from_bytes(&bytes).unwrap();
+        let idrk = Idrk::from_entry(&rdb.entries[0], &[0; 0x1234]).unwrap();
+        std::fs::create_dir(dir.path().join("data")).unwrap();
+        std::fs::write(dir.path().join("data").join("0x00000001.file"), idrk.to_bytes().unwrap()).unwrap();
+
+        assert_eq!(verify_bytes(&bytes, dir.path()).unwrap(), vec![]);
+
+        rdb.entries[0].set_storage_mode(StorageMode::Zlib);
+        assert!(matches!(verify_entries(&rdb, dir.path())[..], [Problem::BadIdrk { ktid: KTID(1), .. }]));
+    }
+
+    #[test]
+    fn every_problem_is_reported() {
+        let dir = tempfile::tempdir().unwrap();
+        let entries = [
+            synthetic_entry(1, 0, "x@5", 1 << 16),
+            synthetic_entry(1, 3, "", 0),
+        ];
+        let mut bytes = synthetic_rdb("data", &entries);
+        bytes.extend_from_slice(&[0; 8]);
+
+        let problems = verify_bytes(&bytes, dir.path()).unwrap();
+
+        assert!(problems.contains(&Problem::TrailingData { file_count: 2, size: 8 }));
+        assert!(problems.contains(&Problem::DuplicateKtid(KTID(1))));
+        assert!(problems.contains(&Problem::SizeMarker { ktid: KTID(1), marker: "5".to_string(), file_size: 0x1234 }));
+        assert!(problems.contains(&Problem::UnknownEntryType { ktid: KTID(1), entry_type: 3 }));
+        assert!(problems.contains(&Problem::MissingFile { ktid: KTID(1), path: dir.path().join("data").join("0x00000001.file") }));
+    }
+
+    #[test]
+    fn file_count_too_high() {
+        let mut bytes = synthetic_rdb("data", &[synthetic_entry(1, 0, "", 0)]);
+        bytes[0x10] = 3;
+
+        let problems = verify_bytes(&bytes, Path::new("")).unwrap();
+        assert!(matches!(problems[..], [Problem::MissingEntries { file_count: 3, parsed: 1, .. }]));
+    }
+}